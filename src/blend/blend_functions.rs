@@ -21,10 +21,22 @@ impl Blend {
     /// warmer/cooler variant of the design color's hue.
     #[must_use]
     pub fn harmonize(design_color: Argb, source_color: Argb) -> Argb {
+        Self::harmonize_with_max(design_color, source_color, 15.0)
+    }
+
+    /// [`Blend::harmonize`] with a custom cap on the hue rotation instead of the standard 15°.
+    ///
+    /// The rotation never exceeds half the hue difference between the two colors, however large
+    /// `max_degrees` is. A `max_degrees` of 0 or less returns `design_color` unchanged.
+    #[must_use]
+    pub fn harmonize_with_max(design_color: Argb, source_color: Argb, max_degrees: f64) -> Argb {
+        if max_degrees <= 0.0 {
+            return design_color;
+        }
         let from_hct = Hct::from_argb(design_color);
         let to_hct = Hct::from_argb(source_color);
         let difference_degrees = MathUtils::difference_degrees(from_hct.hue(), to_hct.hue());
-        let rotation_degrees = (difference_degrees * 0.5).min(15.0);
+        let rotation_degrees = (difference_degrees * 0.5).min(max_degrees);
         let output_hue = MathUtils::sanitize_degrees_double(
             from_hct.hue()
                 + rotation_degrees * MathUtils::rotation_direction(from_hct.hue(), to_hct.hue()),
@@ -32,6 +44,24 @@ impl Blend {
         Hct::new(output_hue, from_hct.chroma(), from_hct.tone()).to_argb()
     }
 
+    /// Harmonizes every color in `design_colors` towards `source_color`.
+    ///
+    /// Each element is identical to calling [`Blend::harmonize`] on it individually.
+    #[must_use]
+    pub fn harmonize_all(design_colors: &[Argb], source_color: Argb) -> Vec<Argb> {
+        design_colors
+            .iter()
+            .map(|&design_color| Self::harmonize(design_color, source_color))
+            .collect()
+    }
+
+    /// In-place variant of [`Blend::harmonize_all`].
+    pub fn harmonize_into(design_colors: &mut [Argb], source_color: Argb) {
+        for design_color in design_colors {
+            *design_color = Self::harmonize(*design_color, source_color);
+        }
+    }
+
     /// Blends hue from one color into another. The chroma and tone of the original color are
     /// maintained.
     ///
@@ -39,13 +69,18 @@ impl Blend {
     ///
     /// * `from`: ARGB representation of color
     /// * `to`: ARGB representation of color
-    /// * `amount`: how much blending to perform; 0.0 >= and <= 1.0
+    /// * `amount`: how much blending to perform; clamped to 0.0..=1.0
     ///
     /// # Returns
     ///
-    /// from, with a hue blended towards to. Chroma and tone are constant.
+    /// from, with a hue blended towards to. Chroma and tone are constant. Unlike
+    /// [`Blend::harmonize`], the rotation is not capped, so an `amount` of 1.0 takes on the hue of
+    /// `to`. An `amount` of 0.0 returns `from` exactly.
     #[must_use]
     pub fn hct_hue(from: Argb, to: Argb, amount: f64) -> Argb {
+        if amount <= 0.0 {
+            return from;
+        }
         let ucs = Self::cam16_ucs(from, to, amount);
         let ucs_cam = Cam16::from_argb(ucs);
         let from_cam = Cam16::from_argb(from);
@@ -59,13 +94,21 @@ impl Blend {
     ///
     /// * `from`: ARGB representation of color
     /// * `to`: ARGB representation of color
-    /// * `amount`: how much blending to perform; 0.0 >= and <= 1.0
+    /// * `amount`: how much blending to perform; clamped to 0.0..=1.0
     ///
     /// # Returns
     ///
-    /// from, blended towards to. Hue, chroma, and tone will change.
+    /// from, blended towards to. Hue, chroma, and tone will change. An `amount` of 0.0 or 1.0
+    /// returns `from` or `to` exactly.
     #[must_use]
     pub fn cam16_ucs(from: Argb, to: Argb, amount: f64) -> Argb {
+        let amount = amount.clamp(0.0, 1.0);
+        if amount <= 0.0 {
+            return from;
+        }
+        if amount >= 1.0 {
+            return to;
+        }
         let from_cam = Cam16::from_argb(from);
         let to_cam = Cam16::from_argb(to);
         let jstar = MathUtils::lerp(from_cam.jstar, to_cam.jstar, amount);
@@ -96,6 +139,46 @@ mod tests {
         assert!((result_hct.tone() - from_hct.tone()).abs() < 1.0);
     }
 
+    #[test]
+    fn test_harmonize_with_max() {
+        let design_color = Argb(0xFFFF0000); // Red, hue ~27
+        let source_color = Argb(0xFF0000FF); // Blue, hue ~283
+        let from_hue = Hct::from_argb(design_color).hue();
+        let rotation = |max_degrees: f64| {
+            let harmonized = Blend::harmonize_with_max(design_color, source_color, max_degrees);
+            MathUtils::difference_degrees(Hct::from_argb(harmonized).hue(), from_hue)
+        };
+
+        assert_eq!(
+            Blend::harmonize_with_max(design_color, source_color, 0.0),
+            design_color
+        );
+        assert_eq!(
+            Blend::harmonize_with_max(design_color, source_color, 15.0),
+            Blend::harmonize(design_color, source_color)
+        );
+        assert!((rotation(15.0) - 15.0).abs() < 1.0, "{}", rotation(15.0));
+        assert!((rotation(45.0) - 45.0).abs() < 1.0, "{}", rotation(45.0));
+        // Capped at half of the ~105° hue difference.
+        assert!((rotation(180.0) - rotation(90.0)).abs() < 1.0);
+    }
+
+    #[test]
+    fn test_harmonize_all() {
+        let source_color = Argb(0xFF0000FF);
+        let colors = [Argb(0xFFFF0000), Argb(0xFF00FF00), Argb(0xFFFFFF00)];
+        let expected: Vec<Argb> = colors
+            .iter()
+            .map(|&c| Blend::harmonize(c, source_color))
+            .collect();
+
+        assert_eq!(Blend::harmonize_all(&colors, source_color), expected);
+
+        let mut in_place = colors;
+        Blend::harmonize_into(&mut in_place, source_color);
+        assert_eq!(in_place.to_vec(), expected);
+    }
+
     #[test]
     fn test_hct_hue() {
         let from = Argb(0xFFFF0000); // Red
@@ -125,4 +208,29 @@ mod tests {
         assert!(result_cam.jstar > from_cam.jstar.min(to_cam.jstar));
         assert!(result_cam.jstar < from_cam.jstar.max(to_cam.jstar));
     }
+
+    #[test]
+    fn test_cam16_ucs_endpoints() {
+        let from = Argb(0xFF4285F4);
+        let to = Argb(0xFFEA4335);
+
+        assert_eq!(Blend::cam16_ucs(from, to, 0.0), from);
+        assert_eq!(Blend::cam16_ucs(from, to, 1.0), to);
+        assert_eq!(Blend::cam16_ucs(from, to, -0.5), from);
+        assert_eq!(Blend::cam16_ucs(from, to, 1.5), to);
+    }
+
+    #[test]
+    fn test_hct_hue_amount_endpoints() {
+        let from = Hct::new(30.0, 30.0, 60.0).to_argb();
+        let to = Argb(0xFF0000FF);
+        assert_eq!(Blend::hct_hue(from, to, 0.0), from);
+
+        let from_hct = Hct::from_argb(from);
+        let blended = Hct::from_argb(Blend::hct_hue(from, to, 1.0));
+        let to_hue = Hct::from_argb(to).hue();
+        assert!(MathUtils::difference_degrees(blended.hue(), to_hue) < 2.0);
+        assert!((blended.chroma() - from_hct.chroma()).abs() < 2.0);
+        assert!((blended.tone() - from_hct.tone()).abs() < 1.0);
+    }
 }
@@ -0,0 +1,49 @@
+/// Named contrast levels, as offered by most platform accessibility settings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ContrastPreset {
+    /// The default contrast, level `0.0`.
+    #[default]
+    Standard,
+    /// Medium contrast, level `0.5`.
+    Medium,
+    /// High contrast, level `1.0`.
+    High,
+}
+
+impl ContrastPreset {
+    /// Every preset, from lowest to highest contrast.
+    #[must_use]
+    pub const fn all() -> &'static [Self] {
+        &[Self::Standard, Self::Medium, Self::High]
+    }
+
+    /// The contrast level this preset stands for, as taken by the scheme builders.
+    #[must_use]
+    pub const fn level(self) -> f64 {
+        match self {
+            Self::Standard => 0.0,
+            Self::Medium => 0.5,
+            Self::High => 1.0,
+        }
+    }
+}
+
+impl From<ContrastPreset> for f64 {
+    fn from(preset: ContrastPreset) -> Self {
+        preset.level()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+    use super::*;
+
+    #[test]
+    fn test_levels() {
+        let levels: Vec<f64> = ContrastPreset::all().iter().map(|p| p.level()).collect();
+        assert_eq!(levels, [0.0, 0.5, 1.0]);
+        assert_eq!(f64::from(ContrastPreset::default()), 0.0);
+    }
+}
@@ -1 +1,4 @@
+pub mod contrast_preset;
 pub mod contrast_utils;
+
+pub use contrast_preset::ContrastPreset;
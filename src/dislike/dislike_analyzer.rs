@@ -1,5 +1,31 @@
 use crate::hct::hct_color::Hct;
 
+/// Thresholds that define a disliked color, see [`DislikeAnalyzer::is_disliked_with`].
+///
+/// The default is the standard "dark yellow-green" rule.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct DislikeConfig {
+    /// Inclusive range of (rounded) hues that can be disliked.
+    pub hue_range: (f64, f64),
+    /// Colors need a (rounded) chroma above this value to be disliked.
+    pub min_chroma: f64,
+    /// Colors need a (rounded) tone below this value to be disliked.
+    pub max_tone: f64,
+    /// Tone that disliked colors are moved to by [`DislikeAnalyzer::fix_if_disliked_with`].
+    pub fixed_tone: f64,
+}
+
+impl Default for DislikeConfig {
+    fn default() -> Self {
+        Self {
+            hue_range: (90.0, 111.0),
+            min_chroma: 16.0,
+            max_tone: 65.0,
+            fixed_tone: 70.0,
+        }
+    }
+}
+
 /// Check and/or fix universally disliked colors.
 ///
 /// Color science studies of color preference indicate universal distaste for dark yellow-greens, and
@@ -15,17 +41,30 @@ impl DislikeAnalyzer {
     /// Disliked is defined as a dark yellow-green that is not neutral.
     #[must_use]
     pub fn is_disliked(hct: &Hct) -> bool {
-        let hue_passes = hct.hue().round() >= 90.0 && hct.hue().round() <= 111.0;
-        let chroma_passes = hct.chroma().round() > 16.0;
-        let tone_passes = hct.tone().round() < 65.0;
+        Self::is_disliked_with(hct, DislikeConfig::default())
+    }
+
+    /// Returns true if color is disliked according to the given thresholds.
+    #[must_use]
+    pub fn is_disliked_with(hct: &Hct, config: DislikeConfig) -> bool {
+        let (min_hue, max_hue) = config.hue_range;
+        let hue_passes = hct.hue().round() >= min_hue && hct.hue().round() <= max_hue;
+        let chroma_passes = hct.chroma().round() > config.min_chroma;
+        let tone_passes = hct.tone().round() < config.max_tone;
         hue_passes && chroma_passes && tone_passes
     }
 
     /// If color is disliked, lighten it to make it likable.
     #[must_use]
     pub fn fix_if_disliked(hct: Hct) -> Hct {
-        if Self::is_disliked(&hct) {
-            Hct::new(hct.hue(), hct.chroma(), 70.0)
+        Self::fix_if_disliked_with(hct, DislikeConfig::default())
+    }
+
+    /// If color is disliked according to the given thresholds, move it to `config.fixed_tone`.
+    #[must_use]
+    pub fn fix_if_disliked_with(hct: Hct, config: DislikeConfig) -> Hct {
+        if Self::is_disliked_with(&hct, config) {
+            Hct::new(hct.hue(), hct.chroma(), config.fixed_tone)
         } else {
             hct
         }
@@ -60,4 +99,35 @@ mod tests {
         assert!(!DislikeAnalyzer::is_disliked(&fixed));
         assert!((fixed.tone() - 70.0).abs() < 1.0);
     }
+
+    #[test]
+    fn test_default_config_matches_is_disliked() {
+        for hue in (0..360).step_by(5) {
+            for tone in (0..=100).step_by(10) {
+                let hct = Hct::new(f64::from(hue), 40.0, f64::from(tone));
+                assert_eq!(
+                    DislikeAnalyzer::is_disliked(&hct),
+                    DislikeAnalyzer::is_disliked_with(&hct, DislikeConfig::default())
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_custom_config() {
+        // Olive-ish orange, outside of the default hue range
+        let hct = Hct::new(75.0, 40.0, 50.0);
+        assert!(!DislikeAnalyzer::is_disliked(&hct));
+
+        let config = DislikeConfig {
+            hue_range: (60.0, 120.0),
+            fixed_tone: 80.0,
+            ..DislikeConfig::default()
+        };
+        assert!(DislikeAnalyzer::is_disliked_with(&hct, config));
+
+        let fixed = DislikeAnalyzer::fix_if_disliked_with(hct, config);
+        assert!((fixed.tone() - 80.0).abs() < 1.0);
+        assert!(!DislikeAnalyzer::is_disliked_with(&fixed, config));
+    }
 }
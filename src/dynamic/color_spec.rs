@@ -3,6 +3,9 @@ use crate::dynamic::dynamic_scheme::DynamicScheme;
 use crate::dynamic::variant::Variant;
 use crate::hct::hct_color::Hct;
 use crate::palettes::tonal_palette::TonalPalette;
+use crate::utils::error::SpecVersionParseError;
+use std::fmt;
+use std::str::FromStr;
 use std::sync::Arc;
 
 macro_rules! cached_color {
@@ -51,12 +54,70 @@ pub enum SpecVersion {
     Spec2026,
 }
 
+impl SpecVersion {
+    /// Every spec version, ordered oldest → newest.
+    #[must_use]
+    pub const fn all() -> &'static [Self] {
+        &[Self::Spec2021, Self::Spec2025, Self::Spec2026]
+    }
+
+    /// The newest spec version.
+    #[must_use]
+    pub const fn latest() -> Self {
+        Self::Spec2026
+    }
+
+    /// The spec's year, as used by [`Display`](fmt::Display) and [`FromStr`].
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Spec2021 => "2021",
+            Self::Spec2025 => "2025",
+            Self::Spec2026 => "2026",
+        }
+    }
+}
+
+impl fmt::Display for SpecVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for SpecVersion {
+    type Err = SpecVersionParseError;
+
+    /// Parses a spec year such as `"2025"`, optionally prefixed with `spec` (`"Spec2025"`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim();
+        let year = name
+            .get(..4)
+            .filter(|prefix| prefix.eq_ignore_ascii_case("spec"))
+            .map_or(name, |_| &name[4..]);
+        Self::all()
+            .iter()
+            .find(|version| version.as_str() == year)
+            .copied()
+            .ok_or_else(|| SpecVersionParseError(s.to_string()))
+    }
+}
+
 /// The device platform that the scheme is being generated for.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Platform {
     Phone,
     Watch,
+    /// Desktop apps. Currently resolves exactly like [`Platform::Phone`] in every spec version.
+    Desktop,
+}
+
+impl Platform {
+    /// Every platform, in declaration order.
+    #[must_use]
+    pub const fn all() -> &'static [Self] {
+        &[Self::Phone, Self::Watch, Self::Desktop]
+    }
 }
 
 /// An interface defining all the necessary methods that could differ between
@@ -240,3 +301,26 @@ pub trait ColorSpec: Send + Sync {
         contrast_level: f64,
     ) -> TonalPalette;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spec_version_parsing() {
+        assert_eq!("2026".parse::<SpecVersion>(), Ok(SpecVersion::Spec2026));
+        assert_eq!("spec2021".parse::<SpecVersion>(), Ok(SpecVersion::Spec2021));
+        assert!("2024".parse::<SpecVersion>().is_err());
+        for &version in SpecVersion::all() {
+            assert_eq!(version.to_string().parse::<SpecVersion>(), Ok(version));
+        }
+    }
+
+    #[test]
+    fn test_latest_is_newest() {
+        assert_eq!(
+            SpecVersion::all().iter().max(),
+            Some(&SpecVersion::latest())
+        );
+    }
+}
@@ -963,7 +963,11 @@ impl ColorSpec for ColorSpec2021 {
                         if s.is_dark { 30.0 } else { 90.0 }
                     } else {
                         let proposed = s.tertiary_palette.get_hct(s.source_color_hct().tone());
-                        DislikeAnalyzer::fix_if_disliked(proposed).tone()
+                        if s.fix_disliked_colors {
+                            DislikeAnalyzer::fix_if_disliked(proposed).tone()
+                        } else {
+                            proposed.tone()
+                        }
                     }
                 })),
                 None,
@@ -1586,7 +1590,7 @@ impl ColorSpec for ColorSpec2021 {
                         f_tone = f_tone.min(delta.mul_add(expansion_dir, n_tone));
                     }
                 } else {
-                    f_tone = if expansion_dir > 0.0 { 60.0 } else { 49.0 };
+                    f_tone = DynamicColor::avoid_dead_zone(f_tone, expansion_dir <= 0.0);
                 }
             }
 
@@ -1613,12 +1617,11 @@ impl ColorSpec for ColorSpec2021 {
                 answer = DynamicColor::foreground_tone(bg_tone, desired_ratio);
             }
 
-            if color.is_background && (50.0..60.0).contains(&answer) {
-                answer = if Contrast::ratio_of_tones(49.0, bg_tone) >= desired_ratio {
-                    49.0
-                } else {
-                    60.0
-                };
+            if color.is_background {
+                answer = DynamicColor::avoid_dead_zone(
+                    answer,
+                    Contrast::ratio_of_tones(49.0, bg_tone) >= desired_ratio,
+                );
             }
 
             // Handle Second Background
@@ -1,17 +1,20 @@
-use std::sync::Arc;
+use std::sync::{Arc, OnceLock};
 
 use crate::contrast::contrast_utils::Contrast;
 use crate::dynamic::color_spec::{ColorSpec, Platform, SpecVersion};
 use crate::dynamic::color_spec_2021::ColorSpec2021;
 use crate::dynamic::color_specs::ColorSpecs;
 use crate::dynamic::contrast_curve::ContrastCurve;
-use crate::dynamic::dynamic_color::{ContrastConstraints, DynamicColor};
+use crate::dynamic::dynamic_color::{ContrastConstraints, DynamicColor, DynamicColorFunction};
 use crate::dynamic::dynamic_scheme::DynamicScheme;
 use crate::dynamic::tone_delta_pair::{DeltaConstraint, ToneDeltaPair, TonePolarity};
+use crate::dynamic::tone_utils;
 use crate::dynamic::variant::Variant;
 use crate::hct::hct_color::Hct;
 use crate::palettes::tonal_palette::TonalPalette;
 
+type BackgroundFunction = DynamicColorFunction<Option<Arc<DynamicColor>>>;
+
 pub struct ColorSpec2025 {
     base: ColorSpec2021,
     override_spec: SpecVersion,
@@ -37,46 +40,39 @@ impl ColorSpec2025 {
         Self::with_override_spec(SpecVersion::Spec2025)
     }
 
-    fn t_max_c(
-        palette: &TonalPalette,
-        lower_bound: f64,
-        upper_bound: f64,
-        chroma_multiplier: f64,
-    ) -> f64 {
-        let answer = Self::find_best_tone_for_chroma(
-            palette.hue,
-            palette.chroma * chroma_multiplier,
-            100.0,
-            true,
-        );
-        answer.clamp(lower_bound, upper_bound)
-    }
-
-    fn t_min_c(palette: &TonalPalette, lower_bound: f64, upper_bound: f64) -> f64 {
-        let answer = Self::find_best_tone_for_chroma(palette.hue, palette.chroma, 0.0, false);
-        answer.clamp(lower_bound, upper_bound)
-    }
-
-    fn find_best_tone_for_chroma(
-        hue: f64,
-        chroma: f64,
-        mut tone: f64,
-        by_decreasing_tone: bool,
-    ) -> f64 {
-        let mut answer = tone;
-        let mut best_candidate = Hct::new(hue, chroma, answer);
-        while best_candidate.chroma() < chroma {
-            if !(0.0..=100.0).contains(&tone) {
-                break;
-            }
-            tone += if by_decreasing_tone { -1.0 } else { 1.0 };
-            let new_candidate = Hct::new(hue, chroma, tone);
-            if best_candidate.chroma() < new_candidate.chroma() {
-                best_candidate = new_candidate;
-                answer = tone;
-            }
-        }
-        answer
+    /// The contrast background shared by most roles: the highest surface of the scheme's spec.
+    /// Every role holds the same closure rather than allocating its own.
+    fn highest_surface_background() -> BackgroundFunction {
+        static BACKGROUND: OnceLock<BackgroundFunction> = OnceLock::new();
+        Arc::clone(BACKGROUND.get_or_init(|| {
+            Arc::new(|s| Some(ColorSpecs::get(s.spec_version).call().highest_surface(s)))
+        }))
+    }
+
+    /// Like [`Self::highest_surface_background`], but without a background on watches.
+    fn phone_highest_surface_background() -> BackgroundFunction {
+        static BACKGROUND: OnceLock<BackgroundFunction> = OnceLock::new();
+        Arc::clone(BACKGROUND.get_or_init(|| {
+            Arc::new(|s| {
+                (s.platform != Platform::Watch)
+                    .then(|| ColorSpecs::get(s.spec_version).call().highest_surface(s))
+            })
+        }))
+    }
+
+    /// The contrast background of the `*_dim` roles: `surface_container_high` of the scheme's
+    /// spec.
+    fn surface_container_high_background() -> BackgroundFunction {
+        static BACKGROUND: OnceLock<BackgroundFunction> = OnceLock::new();
+        Arc::clone(BACKGROUND.get_or_init(|| {
+            Arc::new(|s| {
+                Some(
+                    ColorSpecs::get(s.spec_version)
+                        .call()
+                        .surface_container_high(),
+                )
+            })
+        }))
     }
 
     fn get_contrast_curve(default_contrast: f64) -> ContrastCurve {
@@ -146,7 +142,7 @@ impl ColorSpec for ColorSpec2025 {
                 Arc::new(|s| s.neutral_palette.clone()),
                 true,
                 Some(Arc::new(|s| {
-                    if s.platform == Platform::Phone {
+                    if s.platform != Platform::Watch {
                         if s.is_dark {
                             4.0
                         } else if Hct::is_yellow(s.neutral_palette.hue) {
@@ -378,7 +374,7 @@ impl ColorSpec for ColorSpec2025 {
                 Arc::new(|s| s.neutral_palette.clone()),
                 true,
                 Some(Arc::new(|s| {
-                    if s.platform == Platform::Phone {
+                    if s.platform != Platform::Watch {
                         if s.is_dark {
                             6.0
                         } else if Hct::is_yellow(s.neutral_palette.hue) {
@@ -393,7 +389,7 @@ impl ColorSpec for ColorSpec2025 {
                     }
                 })),
                 Some(Arc::new(|s| {
-                    if s.platform == Platform::Phone {
+                    if s.platform != Platform::Watch {
                         match s.variant {
                             Variant::Neutral => 1.3,
                             Variant::TonalSpot => 1.25,
@@ -428,7 +424,7 @@ impl ColorSpec for ColorSpec2025 {
                 Arc::new(|s| s.neutral_palette.clone()),
                 true,
                 Some(Arc::new(|s| {
-                    if s.platform == Platform::Phone {
+                    if s.platform != Platform::Watch {
                         if s.is_dark {
                             9.0
                         } else if Hct::is_yellow(s.neutral_palette.hue) {
@@ -443,7 +439,7 @@ impl ColorSpec for ColorSpec2025 {
                     }
                 })),
                 Some(Arc::new(|s| {
-                    if s.platform == Platform::Phone {
+                    if s.platform != Platform::Watch {
                         match s.variant {
                             Variant::Neutral => 1.6,
                             Variant::TonalSpot => 1.4,
@@ -478,7 +474,7 @@ impl ColorSpec for ColorSpec2025 {
                 Arc::new(|s| s.neutral_palette.clone()),
                 true,
                 Some(Arc::new(|s| {
-                    if s.platform == Platform::Phone {
+                    if s.platform != Platform::Watch {
                         if s.is_dark {
                             12.0
                         } else if Hct::is_yellow(s.neutral_palette.hue) {
@@ -493,7 +489,7 @@ impl ColorSpec for ColorSpec2025 {
                     }
                 })),
                 Some(Arc::new(|s| {
-                    if s.platform == Platform::Phone {
+                    if s.platform != Platform::Watch {
                         match s.variant {
                             Variant::Neutral => 1.9,
                             Variant::TonalSpot => 1.5,
@@ -569,7 +565,7 @@ impl ColorSpec for ColorSpec2025 {
                 false,
                 Some(Arc::new(|s| {
                     if s.variant == Variant::Vibrant {
-                        Self::t_max_c(&s.neutral_palette, 0.0, 100.0, 1.1)
+                        tone_utils::t_max_c(&s.neutral_palette, 0.0, 100.0, 1.1)
                     } else {
                         ColorSpecs::get(s.spec_version)
                             .call()
@@ -578,7 +574,7 @@ impl ColorSpec for ColorSpec2025 {
                     }
                 })),
                 Some(Arc::new(|s| {
-                    if s.platform == Platform::Phone {
+                    if s.platform != Platform::Watch {
                         match s.variant {
                             Variant::Neutral => 2.2,
                             Variant::TonalSpot => 1.7,
@@ -598,12 +594,10 @@ impl ColorSpec for ColorSpec2025 {
                 None,
                 None,
                 Some(ContrastConstraints {
-                    background: Arc::new(|s| {
-                        Some(ColorSpecs::get(s.spec_version).call().highest_surface(s))
-                    }),
+                    background: Self::highest_surface_background(),
                     contrast_curve: Arc::new(|s| {
                         Some(Self::get_contrast_curve(
-                            if s.is_dark && s.platform == Platform::Phone {
+                            if s.is_dark && s.platform != Platform::Watch {
                                 11.0
                             } else {
                                 9.0
@@ -663,7 +657,7 @@ impl ColorSpec for ColorSpec2025 {
                 false,
                 None,
                 Some(Arc::new(|s| {
-                    if s.platform == Platform::Phone {
+                    if s.platform != Platform::Watch {
                         match s.variant {
                             Variant::Neutral => 2.2,
                             Variant::TonalSpot => 1.7,
@@ -683,11 +677,9 @@ impl ColorSpec for ColorSpec2025 {
                 None,
                 None,
                 Some(ContrastConstraints {
-                    background: Arc::new(|s| {
-                        Some(ColorSpecs::get(s.spec_version).call().highest_surface(s))
-                    }),
+                    background: Self::highest_surface_background(),
                     contrast_curve: Arc::new(|s| {
-                        Some(Self::get_contrast_curve(if s.platform == Platform::Phone {
+                        Some(Self::get_contrast_curve(if s.platform != Platform::Watch {
                             if s.is_dark { 6.0 } else { 4.5 }
                         } else {
                             7.0
@@ -752,7 +744,7 @@ impl ColorSpec for ColorSpec2025 {
                 false,
                 None,
                 Some(Arc::new(|s| {
-                    if s.platform == Platform::Phone {
+                    if s.platform != Platform::Watch {
                         match s.variant {
                             Variant::Neutral => 2.2,
                             Variant::TonalSpot => 1.7,
@@ -772,11 +764,9 @@ impl ColorSpec for ColorSpec2025 {
                 None,
                 None,
                 Some(ContrastConstraints {
-                    background: Arc::new(|s| {
-                        Some(ColorSpecs::get(s.spec_version).call().highest_surface(s))
-                    }),
+                    background: Self::highest_surface_background(),
                     contrast_curve: Arc::new(|s| {
-                        Some(Self::get_contrast_curve(if s.platform == Platform::Phone {
+                        Some(Self::get_contrast_curve(if s.platform != Platform::Watch {
                             3.0
                         } else {
                             4.5
@@ -799,7 +789,7 @@ impl ColorSpec for ColorSpec2025 {
                 false,
                 None,
                 Some(Arc::new(|s| {
-                    if s.platform == Platform::Phone {
+                    if s.platform != Platform::Watch {
                         match s.variant {
                             Variant::Neutral => 2.2,
                             Variant::TonalSpot => 1.7,
@@ -819,11 +809,9 @@ impl ColorSpec for ColorSpec2025 {
                 None,
                 None,
                 Some(ContrastConstraints {
-                    background: Arc::new(|s| {
-                        Some(ColorSpecs::get(s.spec_version).call().highest_surface(s))
-                    }),
+                    background: Self::highest_surface_background(),
                     contrast_curve: Arc::new(|s| {
-                        Some(Self::get_contrast_curve(if s.platform == Platform::Phone {
+                        Some(Self::get_contrast_curve(if s.platform != Platform::Watch {
                             1.5
                         } else {
                             3.0
@@ -881,25 +869,25 @@ impl ColorSpec for ColorSpec2025 {
                 true,
                 Some(Arc::new(|s| match s.variant {
                     Variant::Neutral => {
-                        if s.platform == Platform::Phone {
+                        if s.platform != Platform::Watch {
                             if s.is_dark { 80.0 } else { 40.0 }
                         } else {
                             90.0
                         }
                     }
                     Variant::TonalSpot => {
-                        if s.platform == Platform::Phone {
+                        if s.platform != Platform::Watch {
                             if s.is_dark {
                                 80.0
                             } else {
-                                Self::t_max_c(&s.primary_palette, 0.0, 100.0, 1.0)
+                                tone_utils::t_max_c(&s.primary_palette, 0.0, 100.0, 1.0)
                             }
                         } else {
-                            Self::t_max_c(&s.primary_palette, 0.0, 90.0, 1.0)
+                            tone_utils::t_max_c(&s.primary_palette, 0.0, 90.0, 1.0)
                         }
                     }
                     Variant::Expressive => {
-                        if s.platform == Platform::Phone {
+                        if s.platform != Platform::Watch {
                             let hue = s.primary_palette.hue;
                             let upper = if Hct::is_yellow(hue) {
                                 25.0
@@ -908,28 +896,28 @@ impl ColorSpec for ColorSpec2025 {
                             } else {
                                 98.0
                             };
-                            Self::t_max_c(&s.primary_palette, 0.0, upper, 1.0)
+                            tone_utils::t_max_c(&s.primary_palette, 0.0, upper, 1.0)
                         } else {
-                            Self::t_max_c(&s.primary_palette, 0.0, 100.0, 1.0)
+                            tone_utils::t_max_c(&s.primary_palette, 0.0, 100.0, 1.0)
                         }
                     }
                     _ => {
-                        if s.platform == Platform::Phone {
+                        if s.platform != Platform::Watch {
                             let upper = if Hct::is_cyan(s.primary_palette.hue) {
                                 88.0
                             } else {
                                 98.0
                             };
-                            Self::t_max_c(&s.primary_palette, 0.0, upper, 1.0)
+                            tone_utils::t_max_c(&s.primary_palette, 0.0, upper, 1.0)
                         } else {
-                            Self::t_max_c(&s.primary_palette, 0.0, 100.0, 1.0)
+                            tone_utils::t_max_c(&s.primary_palette, 0.0, 100.0, 1.0)
                         }
                     }
                 })),
                 None,
                 Some(Arc::new(|s| {
                     let spec = ColorSpecs::get(s.spec_version).call();
-                    if s.platform == Platform::Phone {
+                    if s.platform != Platform::Watch {
                         Some(ToneDeltaPair::new(
                             spec.primary_container(),
                             spec.primary(),
@@ -944,11 +932,9 @@ impl ColorSpec for ColorSpec2025 {
                 })),
                 None,
                 Some(ContrastConstraints {
-                    background: Arc::new(|s| {
-                        Some(ColorSpecs::get(s.spec_version).call().highest_surface(s))
-                    }),
+                    background: Self::highest_surface_background(),
                     contrast_curve: Arc::new(|s| {
-                        Some(Self::get_contrast_curve(if s.platform == Platform::Phone {
+                        Some(Self::get_contrast_curve(if s.platform != Platform::Watch {
                             4.5
                         } else {
                             7.0
@@ -972,8 +958,8 @@ impl ColorSpec for ColorSpec2025 {
                 true,
                 Some(Arc::new(|s| match s.variant {
                     Variant::Neutral => 85.0,
-                    Variant::TonalSpot => Self::t_max_c(&s.primary_palette, 0.0, 90.0, 1.0),
-                    _ => Self::t_max_c(&s.primary_palette, 0.0, 100.0, 1.0),
+                    Variant::TonalSpot => tone_utils::t_max_c(&s.primary_palette, 0.0, 90.0, 1.0),
+                    _ => tone_utils::t_max_c(&s.primary_palette, 0.0, 100.0, 1.0),
                 })),
                 None,
                 Some(Arc::new(move |_s| {
@@ -992,13 +978,7 @@ impl ColorSpec for ColorSpec2025 {
                 })),
                 None,
                 Some(ContrastConstraints {
-                    background: Arc::new(|s| {
-                        Some(
-                            ColorSpecs::get(s.spec_version)
-                                .call()
-                                .surface_container_high(),
-                        )
-                    }),
+                    background: Self::surface_container_high_background(),
                     contrast_curve: Arc::new(|_| Some(Self::get_contrast_curve(4.5))),
                     second_background: None,
                 }),
@@ -1019,14 +999,14 @@ impl ColorSpec for ColorSpec2025 {
                 Some(ContrastConstraints {
                     background: Arc::new(|s| {
                         let spec = ColorSpecs::get(s.spec_version).call();
-                        if s.platform == Platform::Phone {
+                        if s.platform != Platform::Watch {
                             Some(spec.primary())
                         } else {
                             spec.primary_dim()
                         }
                     }),
                     contrast_curve: Arc::new(|s| {
-                        Some(Self::get_contrast_curve(if s.platform == Platform::Phone {
+                        Some(Self::get_contrast_curve(if s.platform != Platform::Watch {
                             6.0
                         } else {
                             7.0
@@ -1061,33 +1041,33 @@ impl ColorSpec for ColorSpec2025 {
                             }
                             Variant::TonalSpot => {
                                 if s.is_dark {
-                                    Self::t_min_c(&s.primary_palette, 35.0, 93.0)
+                                    tone_utils::t_min_c(&s.primary_palette, 35.0, 93.0)
                                 } else {
-                                    Self::t_max_c(&s.primary_palette, 0.0, 90.0, 1.0)
+                                    tone_utils::t_max_c(&s.primary_palette, 0.0, 90.0, 1.0)
                                 }
                             }
                             Variant::Expressive => {
                                 if s.is_dark {
-                                    Self::t_max_c(&s.primary_palette, 30.0, 93.0, 1.0)
+                                    tone_utils::t_max_c(&s.primary_palette, 30.0, 93.0, 1.0)
                                 } else {
                                     let upper = if Hct::is_cyan(s.primary_palette.hue) {
                                         88.0
                                     } else {
                                         90.0
                                     };
-                                    Self::t_max_c(&s.primary_palette, 78.0, upper, 1.0)
+                                    tone_utils::t_max_c(&s.primary_palette, 78.0, upper, 1.0)
                                 }
                             }
                             _ => {
                                 if s.is_dark {
-                                    Self::t_min_c(&s.primary_palette, 66.0, 93.0)
+                                    tone_utils::t_min_c(&s.primary_palette, 66.0, 93.0)
                                 } else {
                                     let upper = if Hct::is_cyan(s.primary_palette.hue) {
                                         88.0
                                     } else {
                                         93.0
                                     };
-                                    Self::t_max_c(&s.primary_palette, 66.0, upper, 1.0)
+                                    tone_utils::t_max_c(&s.primary_palette, 66.0, upper, 1.0)
                                 }
                             }
                         }
@@ -1111,15 +1091,9 @@ impl ColorSpec for ColorSpec2025 {
                 })),
                 None,
                 Some(ContrastConstraints {
-                    background: Arc::new(|s| {
-                        if s.platform == Platform::Phone {
-                            Some(ColorSpecs::get(s.spec_version).call().highest_surface(s))
-                        } else {
-                            None
-                        }
-                    }),
+                    background: Self::phone_highest_surface_background(),
                     contrast_curve: Arc::new(|s| {
-                        if s.platform == Platform::Phone && s.contrast_level > 0.0 {
+                        if s.platform != Platform::Watch && s.contrast_level > 0.0 {
                             Some(Self::get_contrast_curve(1.5))
                         } else {
                             None
@@ -1149,7 +1123,7 @@ impl ColorSpec for ColorSpec2025 {
                         Some(ColorSpecs::get(s.spec_version).call().primary_container())
                     }),
                     contrast_curve: Arc::new(|s| {
-                        Some(Self::get_contrast_curve(if s.platform == Platform::Phone {
+                        Some(Self::get_contrast_curve(if s.platform != Platform::Watch {
                             6.0
                         } else {
                             7.0
@@ -1171,7 +1145,7 @@ impl ColorSpec for ColorSpec2025 {
                 Arc::new(|s| s.primary_palette.clone()),
                 false,
                 Some(Arc::new(|s| {
-                    Self::t_max_c(&s.primary_palette, 0.0, 100.0, 1.0)
+                    tone_utils::t_max_c(&s.primary_palette, 0.0, 100.0, 1.0)
                 })),
                 None,
                 None,
@@ -1181,7 +1155,7 @@ impl ColorSpec for ColorSpec2025 {
                         Some(ColorSpecs::get(s.spec_version).call().inverse_surface())
                     }),
                     contrast_curve: Arc::new(|s| {
-                        Some(Self::get_contrast_curve(if s.platform == Platform::Phone {
+                        Some(Self::get_contrast_curve(if s.platform != Platform::Watch {
                             6.0
                         } else {
                             7.0
@@ -1209,18 +1183,18 @@ impl ColorSpec for ColorSpec2025 {
                         if s.variant == Variant::Neutral {
                             90.0
                         } else {
-                            Self::t_max_c(&s.secondary_palette, 0.0, 90.0, 1.0)
+                            tone_utils::t_max_c(&s.secondary_palette, 0.0, 90.0, 1.0)
                         }
                     } else {
                         match s.variant {
                             Variant::Neutral => {
                                 if s.is_dark {
-                                    Self::t_min_c(&s.secondary_palette, 0.0, 98.0)
+                                    tone_utils::t_min_c(&s.secondary_palette, 0.0, 98.0)
                                 } else {
-                                    Self::t_max_c(&s.secondary_palette, 0.0, 100.0, 1.0)
+                                    tone_utils::t_max_c(&s.secondary_palette, 0.0, 100.0, 1.0)
                                 }
                             }
-                            Variant::Vibrant => Self::t_max_c(
+                            Variant::Vibrant => tone_utils::t_max_c(
                                 &s.secondary_palette,
                                 0.0,
                                 if s.is_dark { 90.0 } else { 98.0 },
@@ -1230,7 +1204,7 @@ impl ColorSpec for ColorSpec2025 {
                                 if s.is_dark {
                                     80.0
                                 } else {
-                                    Self::t_max_c(&s.secondary_palette, 0.0, 100.0, 1.0)
+                                    tone_utils::t_max_c(&s.secondary_palette, 0.0, 100.0, 1.0)
                                 }
                             }
                         }
@@ -1238,7 +1212,7 @@ impl ColorSpec for ColorSpec2025 {
                 })),
                 None,
                 Some(Arc::new(|s| {
-                    if s.platform == Platform::Phone {
+                    if s.platform != Platform::Watch {
                         let spec = ColorSpecs::get(s.spec_version).call();
                         Some(ToneDeltaPair::new(
                             spec.secondary_container(),
@@ -1254,11 +1228,9 @@ impl ColorSpec for ColorSpec2025 {
                 })),
                 None,
                 Some(ContrastConstraints {
-                    background: Arc::new(|s| {
-                        Some(ColorSpecs::get(s.spec_version).call().highest_surface(s))
-                    }),
+                    background: Self::highest_surface_background(),
                     contrast_curve: Arc::new(|s| {
-                        Some(Self::get_contrast_curve(if s.platform == Platform::Phone {
+                        Some(Self::get_contrast_curve(if s.platform != Platform::Watch {
                             4.5
                         } else {
                             7.0
@@ -1284,7 +1256,7 @@ impl ColorSpec for ColorSpec2025 {
                     if s.variant == Variant::Neutral {
                         85.0
                     } else {
-                        Self::t_max_c(&s.secondary_palette, 0.0, 90.0, 1.0)
+                        tone_utils::t_max_c(&s.secondary_palette, 0.0, 90.0, 1.0)
                     }
                 })),
                 None,
@@ -1303,13 +1275,7 @@ impl ColorSpec for ColorSpec2025 {
                 })),
                 None,
                 Some(ContrastConstraints {
-                    background: Arc::new(|s| {
-                        Some(
-                            ColorSpecs::get(s.spec_version)
-                                .call()
-                                .surface_container_high(),
-                        )
-                    }),
+                    background: Self::surface_container_high_background(),
                     contrast_curve: Arc::new(|_| Some(Self::get_contrast_curve(4.5))),
                     second_background: None,
                 }),
@@ -1330,14 +1296,14 @@ impl ColorSpec for ColorSpec2025 {
                 Some(ContrastConstraints {
                     background: Arc::new(|s| {
                         let spec = ColorSpecs::get(s.spec_version).call();
-                        if s.platform == Platform::Phone {
+                        if s.platform != Platform::Watch {
                             Some(spec.secondary())
                         } else {
                             spec.secondary_dim()
                         }
                     }),
                     contrast_curve: Arc::new(|s| {
-                        Some(Self::get_contrast_curve(if s.platform == Platform::Phone {
+                        Some(Self::get_contrast_curve(if s.platform != Platform::Watch {
                             6.0
                         } else {
                             7.0
@@ -1365,16 +1331,16 @@ impl ColorSpec for ColorSpec2025 {
                         match s.variant {
                             Variant::Vibrant => {
                                 if s.is_dark {
-                                    Self::t_min_c(&s.secondary_palette, 30.0, 40.0)
+                                    tone_utils::t_min_c(&s.secondary_palette, 30.0, 40.0)
                                 } else {
-                                    Self::t_max_c(&s.secondary_palette, 84.0, 90.0, 1.0)
+                                    tone_utils::t_max_c(&s.secondary_palette, 84.0, 90.0, 1.0)
                                 }
                             }
                             Variant::Expressive => {
                                 if s.is_dark {
                                     15.0
                                 } else {
-                                    Self::t_max_c(&s.secondary_palette, 90.0, 95.0, 1.0)
+                                    tone_utils::t_max_c(&s.secondary_palette, 90.0, 95.0, 1.0)
                                 }
                             }
                             _ => {
@@ -1405,15 +1371,9 @@ impl ColorSpec for ColorSpec2025 {
                 })),
                 None,
                 Some(ContrastConstraints {
-                    background: Arc::new(|s| {
-                        if s.platform == Platform::Phone {
-                            Some(ColorSpecs::get(s.spec_version).call().highest_surface(s))
-                        } else {
-                            None
-                        }
-                    }),
+                    background: Self::phone_highest_surface_background(),
                     contrast_curve: Arc::new(|s| {
-                        if s.platform == Platform::Phone && s.contrast_level > 0.0 {
+                        if s.platform != Platform::Watch && s.contrast_level > 0.0 {
                             Some(Self::get_contrast_curve(1.5))
                         } else {
                             None
@@ -1443,7 +1403,7 @@ impl ColorSpec for ColorSpec2025 {
                         Some(ColorSpecs::get(s.spec_version).call().secondary_container())
                     }),
                     contrast_curve: Arc::new(|s| {
-                        Some(Self::get_contrast_curve(if s.platform == Platform::Phone {
+                        Some(Self::get_contrast_curve(if s.platform != Platform::Watch {
                             6.0
                         } else {
                             7.0
@@ -1469,9 +1429,9 @@ impl ColorSpec for ColorSpec2025 {
                 Some(Arc::new(|s| {
                     if s.platform == Platform::Watch {
                         if s.variant == Variant::TonalSpot {
-                            Self::t_max_c(&s.tertiary_palette, 0.0, 90.0, 1.0)
+                            tone_utils::t_max_c(&s.tertiary_palette, 0.0, 90.0, 1.0)
                         } else {
-                            Self::t_max_c(&s.tertiary_palette, 0.0, 100.0, 1.0)
+                            tone_utils::t_max_c(&s.tertiary_palette, 0.0, 100.0, 1.0)
                         }
                     } else {
                         match s.variant {
@@ -1483,13 +1443,13 @@ impl ColorSpec for ColorSpec2025 {
                                 } else {
                                     100.0
                                 };
-                                Self::t_max_c(&s.tertiary_palette, 0.0, upper, 1.0)
+                                tone_utils::t_max_c(&s.tertiary_palette, 0.0, upper, 1.0)
                             }
                             _ => {
                                 if s.is_dark {
-                                    Self::t_max_c(&s.tertiary_palette, 0.0, 98.0, 1.0)
+                                    tone_utils::t_max_c(&s.tertiary_palette, 0.0, 98.0, 1.0)
                                 } else {
-                                    Self::t_max_c(&s.tertiary_palette, 0.0, 100.0, 1.0)
+                                    tone_utils::t_max_c(&s.tertiary_palette, 0.0, 100.0, 1.0)
                                 }
                             }
                         }
@@ -1497,7 +1457,7 @@ impl ColorSpec for ColorSpec2025 {
                 })),
                 None,
                 Some(Arc::new(|s| {
-                    if s.platform == Platform::Phone {
+                    if s.platform != Platform::Watch {
                         let spec = ColorSpecs::get(s.spec_version).call();
                         Some(ToneDeltaPair::new(
                             spec.tertiary_container(),
@@ -1513,11 +1473,9 @@ impl ColorSpec for ColorSpec2025 {
                 })),
                 None,
                 Some(ContrastConstraints {
-                    background: Arc::new(|s| {
-                        Some(ColorSpecs::get(s.spec_version).call().highest_surface(s))
-                    }),
+                    background: Self::highest_surface_background(),
                     contrast_curve: Arc::new(|s| {
-                        Some(Self::get_contrast_curve(if s.platform == Platform::Phone {
+                        Some(Self::get_contrast_curve(if s.platform != Platform::Watch {
                             4.5
                         } else {
                             7.0
@@ -1541,9 +1499,9 @@ impl ColorSpec for ColorSpec2025 {
                 true,
                 Some(Arc::new(|s| {
                     if s.variant == Variant::TonalSpot {
-                        Self::t_max_c(&s.tertiary_palette, 0.0, 90.0, 1.0)
+                        tone_utils::t_max_c(&s.tertiary_palette, 0.0, 90.0, 1.0)
                     } else {
-                        Self::t_max_c(&s.tertiary_palette, 0.0, 100.0, 1.0)
+                        tone_utils::t_max_c(&s.tertiary_palette, 0.0, 100.0, 1.0)
                     }
                 })),
                 None,
@@ -1562,13 +1520,7 @@ impl ColorSpec for ColorSpec2025 {
                 })),
                 None,
                 Some(ContrastConstraints {
-                    background: Arc::new(|s| {
-                        Some(
-                            ColorSpecs::get(s.spec_version)
-                                .call()
-                                .surface_container_high(),
-                        )
-                    }),
+                    background: Self::surface_container_high_background(),
                     contrast_curve: Arc::new(|_| Some(Self::get_contrast_curve(4.5))),
                     second_background: None,
                 }),
@@ -1589,14 +1541,14 @@ impl ColorSpec for ColorSpec2025 {
                 Some(ContrastConstraints {
                     background: Arc::new(|s| {
                         let spec = ColorSpecs::get(s.spec_version).call();
-                        if s.platform == Platform::Phone {
+                        if s.platform != Platform::Watch {
                             Some(spec.tertiary())
                         } else {
                             spec.tertiary_dim()
                         }
                     }),
                     contrast_curve: Arc::new(|s| {
-                        Some(Self::get_contrast_curve(if s.platform == Platform::Phone {
+                        Some(Self::get_contrast_curve(if s.platform != Platform::Watch {
                             6.0
                         } else {
                             7.0
@@ -1620,20 +1572,20 @@ impl ColorSpec for ColorSpec2025 {
                 Some(Arc::new(|s| {
                     if s.platform == Platform::Watch {
                         if s.variant == Variant::TonalSpot {
-                            Self::t_max_c(&s.tertiary_palette, 0.0, 90.0, 1.0)
+                            tone_utils::t_max_c(&s.tertiary_palette, 0.0, 90.0, 1.0)
                         } else {
-                            Self::t_max_c(&s.tertiary_palette, 0.0, 100.0, 1.0)
+                            tone_utils::t_max_c(&s.tertiary_palette, 0.0, 100.0, 1.0)
                         }
                     } else {
                         match s.variant {
                             Variant::Neutral => {
                                 if s.is_dark {
-                                    Self::t_max_c(&s.tertiary_palette, 0.0, 93.0, 1.0)
+                                    tone_utils::t_max_c(&s.tertiary_palette, 0.0, 93.0, 1.0)
                                 } else {
-                                    Self::t_max_c(&s.tertiary_palette, 0.0, 96.0, 1.0)
+                                    tone_utils::t_max_c(&s.tertiary_palette, 0.0, 96.0, 1.0)
                                 }
                             }
-                            Variant::TonalSpot => Self::t_max_c(
+                            Variant::TonalSpot => tone_utils::t_max_c(
                                 &s.tertiary_palette,
                                 0.0,
                                 if s.is_dark { 93.0 } else { 100.0 },
@@ -1647,13 +1599,13 @@ impl ColorSpec for ColorSpec2025 {
                                 } else {
                                     100.0
                                 };
-                                Self::t_max_c(&s.tertiary_palette, 75.0, upper, 1.0)
+                                tone_utils::t_max_c(&s.tertiary_palette, 75.0, upper, 1.0)
                             }
                             _ => {
                                 if s.is_dark {
-                                    Self::t_max_c(&s.tertiary_palette, 0.0, 93.0, 1.0)
+                                    tone_utils::t_max_c(&s.tertiary_palette, 0.0, 93.0, 1.0)
                                 } else {
-                                    Self::t_max_c(&s.tertiary_palette, 72.0, 100.0, 1.0)
+                                    tone_utils::t_max_c(&s.tertiary_palette, 72.0, 100.0, 1.0)
                                 }
                             }
                         }
@@ -1677,15 +1629,9 @@ impl ColorSpec for ColorSpec2025 {
                 })),
                 None,
                 Some(ContrastConstraints {
-                    background: Arc::new(|s| {
-                        if s.platform == Platform::Phone {
-                            Some(ColorSpecs::get(s.spec_version).call().highest_surface(s))
-                        } else {
-                            None
-                        }
-                    }),
+                    background: Self::phone_highest_surface_background(),
                     contrast_curve: Arc::new(|s| {
-                        if s.platform == Platform::Phone && s.contrast_level > 0.0 {
+                        if s.platform != Platform::Watch && s.contrast_level > 0.0 {
                             Some(Self::get_contrast_curve(1.5))
                         } else {
                             None
@@ -1715,7 +1661,7 @@ impl ColorSpec for ColorSpec2025 {
                         Some(ColorSpecs::get(s.spec_version).call().tertiary_container())
                     }),
                     contrast_curve: Arc::new(|s| {
-                        Some(Self::get_contrast_curve(if s.platform == Platform::Phone {
+                        Some(Self::get_contrast_curve(if s.platform != Platform::Watch {
                             6.0
                         } else {
                             7.0
@@ -1739,20 +1685,20 @@ impl ColorSpec for ColorSpec2025 {
                 Arc::new(|s| s.error_palette.clone()),
                 true,
                 Some(Arc::new(|s| {
-                    if s.platform == Platform::Phone {
+                    if s.platform != Platform::Watch {
                         if s.is_dark {
-                            Self::t_min_c(&s.error_palette, 0.0, 98.0)
+                            tone_utils::t_min_c(&s.error_palette, 0.0, 98.0)
                         } else {
-                            Self::t_max_c(&s.error_palette, 0.0, 100.0, 1.0)
+                            tone_utils::t_max_c(&s.error_palette, 0.0, 100.0, 1.0)
                         }
                     } else {
-                        Self::t_min_c(&s.error_palette, 0.0, 100.0)
+                        tone_utils::t_min_c(&s.error_palette, 0.0, 100.0)
                     }
                 })),
                 None,
                 Some(Arc::new(|s| {
                     let spec = ColorSpecs::get(s.spec_version).call();
-                    if s.platform == Platform::Phone {
+                    if s.platform != Platform::Watch {
                         Some(ToneDeltaPair::new(
                             spec.error_container(),
                             spec.error(),
@@ -1767,11 +1713,9 @@ impl ColorSpec for ColorSpec2025 {
                 })),
                 None,
                 Some(ContrastConstraints {
-                    background: Arc::new(|s| {
-                        Some(ColorSpecs::get(s.spec_version).call().highest_surface(s))
-                    }),
+                    background: Self::highest_surface_background(),
                     contrast_curve: Arc::new(|s| {
-                        Some(Self::get_contrast_curve(if s.platform == Platform::Phone {
+                        Some(Self::get_contrast_curve(if s.platform != Platform::Watch {
                             4.5
                         } else {
                             7.0
@@ -1793,7 +1737,9 @@ impl ColorSpec for ColorSpec2025 {
                 "error_dim".to_string(),
                 Arc::new(|s| s.error_palette.clone()),
                 true,
-                Some(Arc::new(|s| Self::t_min_c(&s.error_palette, 0.0, 100.0))),
+                Some(Arc::new(|s| {
+                    tone_utils::t_min_c(&s.error_palette, 0.0, 100.0)
+                })),
                 None,
                 Some(Arc::new(move |_s| {
                     // Workaround because we don't have inheritance:
@@ -1810,13 +1756,7 @@ impl ColorSpec for ColorSpec2025 {
                 })),
                 None,
                 Some(ContrastConstraints {
-                    background: Arc::new(|s| {
-                        Some(
-                            ColorSpecs::get(s.spec_version)
-                                .call()
-                                .surface_container_high(),
-                        )
-                    }),
+                    background: Self::surface_container_high_background(),
                     contrast_curve: Arc::new(|_| Some(Self::get_contrast_curve(4.5))),
                     second_background: None,
                 }),
@@ -1837,14 +1777,14 @@ impl ColorSpec for ColorSpec2025 {
                 Some(ContrastConstraints {
                     background: Arc::new(|s| {
                         let spec = ColorSpecs::get(s.spec_version).call();
-                        if s.platform == Platform::Phone {
+                        if s.platform != Platform::Watch {
                             Some(spec.error())
                         } else {
                             spec.error_dim()
                         }
                     }),
                     contrast_curve: Arc::new(|s| {
-                        Some(Self::get_contrast_curve(if s.platform == Platform::Phone {
+                        Some(Self::get_contrast_curve(if s.platform != Platform::Watch {
                             6.0
                         } else {
                             7.0
@@ -1869,9 +1809,9 @@ impl ColorSpec for ColorSpec2025 {
                     if s.platform == Platform::Watch {
                         30.0
                     } else if s.is_dark {
-                        Self::t_min_c(&s.error_palette, 30.0, 93.0)
+                        tone_utils::t_min_c(&s.error_palette, 30.0, 93.0)
                     } else {
-                        Self::t_max_c(&s.error_palette, 0.0, 90.0, 1.0)
+                        tone_utils::t_max_c(&s.error_palette, 0.0, 90.0, 1.0)
                     }
                 })),
                 None,
@@ -1892,15 +1832,9 @@ impl ColorSpec for ColorSpec2025 {
                 })),
                 None,
                 Some(ContrastConstraints {
-                    background: Arc::new(|s| {
-                        if s.platform == Platform::Phone {
-                            Some(ColorSpecs::get(s.spec_version).call().highest_surface(s))
-                        } else {
-                            None
-                        }
-                    }),
+                    background: Self::phone_highest_surface_background(),
                     contrast_curve: Arc::new(|s| {
-                        if s.platform == Platform::Phone && s.contrast_level > 0.0 {
+                        if s.platform != Platform::Watch && s.contrast_level > 0.0 {
                             Some(Self::get_contrast_curve(1.5))
                         } else {
                             None
@@ -1930,7 +1864,7 @@ impl ColorSpec for ColorSpec2025 {
                         Some(ColorSpecs::get(s.spec_version).call().error_container())
                     }),
                     contrast_curve: Arc::new(|s| {
-                        Some(Self::get_contrast_curve(if s.platform == Platform::Phone {
+                        Some(Self::get_contrast_curve(if s.platform != Platform::Watch {
                             4.5
                         } else {
                             7.0
@@ -1964,15 +1898,9 @@ impl ColorSpec for ColorSpec2025 {
                 None,
                 None,
                 Some(ContrastConstraints {
-                    background: Arc::new(|s| {
-                        if s.platform == Platform::Phone {
-                            Some(ColorSpecs::get(s.spec_version).call().highest_surface(s))
-                        } else {
-                            None
-                        }
-                    }),
+                    background: Self::phone_highest_surface_background(),
                     contrast_curve: Arc::new(|s| {
-                        if s.platform == Platform::Phone && s.contrast_level > 0.0 {
+                        if s.platform != Platform::Watch && s.contrast_level > 0.0 {
                             Some(Self::get_contrast_curve(1.5))
                         } else {
                             None
@@ -2085,15 +2013,9 @@ impl ColorSpec for ColorSpec2025 {
                 None,
                 None,
                 Some(ContrastConstraints {
-                    background: Arc::new(|s| {
-                        if s.platform == Platform::Phone {
-                            Some(ColorSpecs::get(s.spec_version).call().highest_surface(s))
-                        } else {
-                            None
-                        }
-                    }),
+                    background: Self::phone_highest_surface_background(),
                     contrast_curve: Arc::new(|s| {
-                        if s.platform == Platform::Phone && s.contrast_level > 0.0 {
+                        if s.platform != Platform::Watch && s.contrast_level > 0.0 {
                             Some(Self::get_contrast_curve(1.5))
                         } else {
                             None
@@ -2206,15 +2128,9 @@ impl ColorSpec for ColorSpec2025 {
                 None,
                 None,
                 Some(ContrastConstraints {
-                    background: Arc::new(|s| {
-                        if s.platform == Platform::Phone {
-                            Some(ColorSpecs::get(s.spec_version).call().highest_surface(s))
-                        } else {
-                            None
-                        }
-                    }),
+                    background: Self::phone_highest_surface_background(),
                     contrast_curve: Arc::new(|s| {
-                        if s.platform == Platform::Phone && s.contrast_level > 0.0 {
+                        if s.platform != Platform::Watch && s.contrast_level > 0.0 {
                             Some(Self::get_contrast_curve(1.5))
                         } else {
                             None
@@ -2386,7 +2302,7 @@ impl ColorSpec for ColorSpec2025 {
                 }
             }
 
-            if color.is_background && !color.name.ends_with("_fixed_dim") {
+            if color.is_background && !color.is_fixed_dim() {
                 self_tone = if self_tone >= 57.0 {
                     self_tone.clamp(65.0, 100.0)
                 } else {
@@ -2413,7 +2329,7 @@ impl ColorSpec for ColorSpec2025 {
                     }
 
                     // Clamping for surfaces
-                    if color.is_background && !color.name.ends_with("_fixed_dim") {
+                    if color.is_background && !color.is_fixed_dim() {
                         answer = if answer >= 57.0 {
                             answer.clamp(65.0, 100.0)
                         } else {
@@ -2445,7 +2361,7 @@ impl ColorSpec for ColorSpec2025 {
                     }
                 } else {
                     // Background clamping still applies even if contrast constraints are missing values
-                    if color.is_background && !color.name.ends_with("_fixed_dim") {
+                    if color.is_background && !color.is_fixed_dim() {
                         answer = if answer >= 57.0 {
                             answer.clamp(65.0, 100.0)
                         } else {
@@ -2455,7 +2371,7 @@ impl ColorSpec for ColorSpec2025 {
                 }
             } else {
                 // Simple logic for colors with no contrast constraints at all
-                if color.is_background && !color.name.ends_with("_fixed_dim") {
+                if color.is_background && !color.is_fixed_dim() {
                     answer = if answer >= 57.0 {
                         answer.clamp(65.0, 100.0)
                     } else {
@@ -2479,7 +2395,7 @@ impl ColorSpec for ColorSpec2025 {
         match variant {
             Variant::Neutral => TonalPalette::from_hue_and_chroma(
                 source_color_hct.hue(),
-                if platform == Platform::Phone {
+                if platform != Platform::Watch {
                     if Hct::is_blue(source_color_hct.hue()) {
                         12.0
                     } else {
@@ -2493,7 +2409,7 @@ impl ColorSpec for ColorSpec2025 {
             ),
             Variant::TonalSpot => TonalPalette::from_hue_and_chroma(
                 source_color_hct.hue(),
-                if platform == Platform::Phone && is_dark {
+                if platform != Platform::Watch && is_dark {
                     26.0
                 } else {
                     32.0
@@ -2501,7 +2417,7 @@ impl ColorSpec for ColorSpec2025 {
             ),
             Variant::Expressive => TonalPalette::from_hue_and_chroma(
                 source_color_hct.hue(),
-                if platform == Platform::Phone {
+                if platform != Platform::Watch {
                     if is_dark { 36.0 } else { 48.0 }
                 } else {
                     40.0
@@ -2509,7 +2425,7 @@ impl ColorSpec for ColorSpec2025 {
             ),
             Variant::Vibrant => TonalPalette::from_hue_and_chroma(
                 source_color_hct.hue(),
-                if platform == Platform::Phone {
+                if platform != Platform::Watch {
                     74.0
                 } else {
                     56.0
@@ -2536,7 +2452,7 @@ impl ColorSpec for ColorSpec2025 {
         match variant {
             Variant::Neutral => TonalPalette::from_hue_and_chroma(
                 source_color_hct.hue(),
-                if platform == Platform::Phone {
+                if platform != Platform::Watch {
                     if Hct::is_blue(source_color_hct.hue()) {
                         6.0
                     } else {
@@ -2555,7 +2471,7 @@ impl ColorSpec for ColorSpec2025 {
                     &[0.0, 105.0, 140.0, 204.0, 253.0, 278.0, 300.0, 333.0, 360.0],
                     &[-160.0, 155.0, -100.0, 96.0, -96.0, -156.0, -165.0, -160.0],
                 ),
-                if platform == Platform::Phone {
+                if platform != Platform::Watch {
                     if is_dark { 16.0 } else { 24.0 }
                 } else {
                     24.0
@@ -2567,7 +2483,7 @@ impl ColorSpec for ColorSpec2025 {
                     &[0.0, 38.0, 105.0, 140.0, 333.0, 360.0],
                     &[-14.0, 10.0, -14.0, 10.0, -14.0],
                 ),
-                if platform == Platform::Phone {
+                if platform != Platform::Watch {
                     56.0
                 } else {
                     36.0
@@ -2598,7 +2514,7 @@ impl ColorSpec for ColorSpec2025 {
                     &[0.0, 38.0, 105.0, 161.0, 204.0, 278.0, 333.0, 360.0],
                     &[-32.0, 26.0, 10.0, -39.0, 24.0, -15.0, -32.0],
                 ),
-                if platform == Platform::Phone {
+                if platform != Platform::Watch {
                     20.0
                 } else {
                     36.0
@@ -2610,7 +2526,7 @@ impl ColorSpec for ColorSpec2025 {
                     &[0.0, 20.0, 71.0, 161.0, 333.0, 360.0],
                     &[-40.0, 48.0, -32.0, 40.0, -32.0],
                 ),
-                if platform == Platform::Phone {
+                if platform != Platform::Watch {
                     28.0
                 } else {
                     32.0
@@ -2653,7 +2569,7 @@ impl ColorSpec for ColorSpec2025 {
         match variant {
             Variant::Neutral => TonalPalette::from_hue_and_chroma(
                 source_color_hct.hue(),
-                if platform == Platform::Phone {
+                if platform != Platform::Watch {
                     1.4
                 } else {
                     6.0
@@ -2661,7 +2577,7 @@ impl ColorSpec for ColorSpec2025 {
             ),
             Variant::TonalSpot => TonalPalette::from_hue_and_chroma(
                 source_color_hct.hue(),
-                if platform == Platform::Phone {
+                if platform != Platform::Watch {
                     5.0
                 } else {
                     10.0
@@ -2673,7 +2589,7 @@ impl ColorSpec for ColorSpec2025 {
                     &[0.0, 71.0, 124.0, 253.0, 278.0, 300.0, 360.0],
                     &[10.0, 0.0, 10.0, 0.0, 10.0, 0.0],
                 );
-                let c = if platform == Platform::Phone {
+                let c = if platform != Platform::Watch {
                     if is_dark {
                         if Hct::is_yellow(h) { 6.0 } else { 14.0 }
                     } else {
@@ -2690,7 +2606,7 @@ impl ColorSpec for ColorSpec2025 {
                     &[0.0, 38.0, 105.0, 140.0, 333.0, 360.0],
                     &[-14.0, 10.0, -14.0, 10.0, -14.0],
                 );
-                let c = if platform == Platform::Phone || Hct::is_blue(h) {
+                let c = if platform != Platform::Watch || Hct::is_blue(h) {
                     28.0
                 } else {
                     20.0
@@ -2718,7 +2634,7 @@ impl ColorSpec for ColorSpec2025 {
         match variant {
             Variant::Neutral => TonalPalette::from_hue_and_chroma(
                 source_color_hct.hue(),
-                (if platform == Platform::Phone {
+                (if platform != Platform::Watch {
                     1.4
                 } else {
                     6.0
@@ -2726,7 +2642,7 @@ impl ColorSpec for ColorSpec2025 {
             ),
             Variant::TonalSpot => TonalPalette::from_hue_and_chroma(
                 source_color_hct.hue(),
-                (if platform == Platform::Phone {
+                (if platform != Platform::Watch {
                     5.0
                 } else {
                     10.0
@@ -2738,7 +2654,7 @@ impl ColorSpec for ColorSpec2025 {
                     &[0.0, 71.0, 124.0, 253.0, 278.0, 300.0, 360.0],
                     &[10.0, 0.0, 10.0, 0.0, 10.0, 0.0],
                 );
-                let c = if platform == Platform::Phone {
+                let c = if platform != Platform::Watch {
                     if is_dark {
                         if Hct::is_yellow(h) { 6.0 } else { 14.0 }
                     } else {
@@ -2762,7 +2678,7 @@ impl ColorSpec for ColorSpec2025 {
                     &[0.0, 38.0, 105.0, 140.0, 333.0, 360.0],
                     &[-14.0, 10.0, -14.0, 10.0, -14.0],
                 );
-                let c = if platform == Platform::Phone || Hct::is_blue(h) {
+                let c = if platform != Platform::Watch || Hct::is_blue(h) {
                     28.0
                 } else {
                     20.0
@@ -2795,7 +2711,7 @@ impl ColorSpec for ColorSpec2025 {
         match variant {
             Variant::Neutral => TonalPalette::from_hue_and_chroma(
                 error_hue,
-                if platform == Platform::Phone {
+                if platform != Platform::Watch {
                     50.0
                 } else {
                     40.0
@@ -2803,7 +2719,7 @@ impl ColorSpec for ColorSpec2025 {
             ),
             Variant::TonalSpot => TonalPalette::from_hue_and_chroma(
                 error_hue,
-                if platform == Platform::Phone {
+                if platform != Platform::Watch {
                     60.0
                 } else {
                     48.0
@@ -2811,7 +2727,7 @@ impl ColorSpec for ColorSpec2025 {
             ),
             Variant::Expressive => TonalPalette::from_hue_and_chroma(
                 error_hue,
-                if platform == Platform::Phone {
+                if platform != Platform::Watch {
                     64.0
                 } else {
                     48.0
@@ -2819,7 +2735,7 @@ impl ColorSpec for ColorSpec2025 {
             ),
             Variant::Vibrant => TonalPalette::from_hue_and_chroma(
                 error_hue,
-                if platform == Platform::Phone {
+                if platform != Platform::Watch {
                     80.0
                 } else {
                     60.0
@@ -2835,3 +2751,179 @@ impl ColorSpec for ColorSpec2025 {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::material_dynamic_colors::MaterialDynamicColors;
+    use crate::scheme::{SchemeCmf, SchemeTonalSpot};
+    use crate::utils::color_utils::Argb;
+
+    /// Every role of a Spec2025 TonalSpot and a Spec2026 CMF scheme, in the order of
+    /// [`MaterialDynamicColors::all_dynamic_colors`], as resolved before the roles shared their
+    /// background closures.
+    const BASELINE: [&[u32]; 6] = [
+        // Spec2025, light, Phone.
+        &[
+            0xFF6177A5, 0xFF6F778B, 0xFF816F9A, 0xFF76777C, 0xFF747780, 0xFFC84F4C, 0xFFFAF9FE,
+            0xFF21242C, 0xFFFAF9FE, 0xFFD8D9E4, 0xFFFAF9FE, 0xFFFFFFFF, 0xFFF3F3FA, 0xFFEDEDF6,
+            0xFFE7E7F1, 0xFFE0E2ED, 0xFF21242C, 0xFFE0E2ED, 0xFF40434B, 0xFF5C5F68, 0xFF787A84,
+            0xFF0D0E12, 0xFFC5C4CA, 0xFF000000, 0xFF000000, 0xFF2C426D, 0xFF2C426D, 0xFF203761,
+            0xFFCBDAFF, 0xFF5F74A2, 0xFFFFFFFF, 0xFF5F74A2, 0xFF526895, 0xFFFFFFFF, 0xFFFFFFFF,
+            0xFFB3C9FC, 0xFF3B4355, 0xFF303849, 0xFFD2D9F0, 0xFF6D7488, 0xFFFFFFFF, 0xFF6D7488,
+            0xFF60687B, 0xFFFFFFFF, 0xFFFFFFFF, 0xFF4C3B63, 0xFF403057, 0xFFE6D1FF, 0xFF7F6D98,
+            0xFFFFFFFF, 0xFF7F6D98, 0xFF72608A, 0xFFFFFFFF, 0xFFFFFFFF, 0xFF811B1D, 0xFF67040D,
+            0xFFFFCECA, 0xFFC54D4A, 0xFFFFFFFF,
+        ],
+        // Spec2025, dark, Phone.
+        &[
+            0xFF67779A, 0xFF6F778B, 0xFF816F9A, 0xFF76777C, 0xFF747780, 0xFFC84F4C, 0xFF0D0E12,
+            0xFFFFFFFF, 0xFF0D0E12, 0xFF0D0E12, 0xFF292C34, 0xFF000000, 0xFF111318, 0xFF171A1F,
+            0xFF1D2026, 0xFF23262D, 0xFFFFFFFF, 0xFF23262D, 0xFFB6B8C2, 0xFF91939D, 0xFF73757E,
+            0xFFFAF9FE, 0xFF37383C, 0xFF000000, 0xFF000000, 0xFFB6C6EE, 0xFFB6C6EE, 0xFFA9B9DF,
+            0xFF263656, 0xFF657498, 0xFFFFFFFF, 0xFFC7D7FF, 0xFFB9C9F0, 0xFF021432, 0xFF283858,
+            0xFF455576, 0xFFBFC6DC, 0xFFB1B8CE, 0xFF2E3647, 0xFF6D7488, 0xFFFFFFFF, 0xFFDBE2F9,
+            0xFFCDD4EA, 0xFF181F30, 0xFF383F51, 0xFFEFDFFF, 0xFFE4CEFF, 0xFF53426A, 0xFFE4CEFF,
+            0xFF4A3961, 0xFFE4CEFF, 0xFFD6C0F0, 0xFF1C0C31, 0xFF403057, 0xFFFF9F99, 0xFFFF8882,
+            0xFF60000A, 0xFFC54D4A, 0xFFFFFFFF,
+        ],
+        // Spec2025, dark, Watch.
+        &[
+            0xFF6177A5, 0xFF6F778B, 0xFF836EA1, 0xFF737782, 0xFF6E778C, 0xFFBB5955, 0xFF000000,
+            0xFFFFFFFF, 0xFF000000, 0xFF0A0E17, 0xFF242C3E, 0xFF000000, 0xFF22262F, 0xFF2C303A,
+            0xFF373B45, 0xFF1D2638, 0xFFE2E5F2, 0xFF1D2638, 0xFFE2E5F2, 0xFFB5B8C5, 0xFF90939F,
+            0xFFF9F9FF, 0xFF343842, 0xFF000000, 0xFF000000, 0xFFDCE5FF, 0xFFDCE5FF, 0xFFB9CEFF,
+            0xFF00183E, 0xFF304671, 0xFFFFFFFF, 0xFF304671, 0xFF243B65, 0xFFFFFEFF, 0xFFB9CEFF,
+            0xFF213761, 0xFFDEE5FC, 0xFFD0D7ED, 0xFF1A2232, 0xFF3F4759, 0xFFFFFFFF, 0xFF3F4759,
+            0xFF343B4D, 0xFFFFFEFF, 0xFFC6CEE4, 0xFFEFDFFF, 0xFFDDC4FD, 0xFF23103D, 0xFFC1A9E0,
+            0xFF000000, 0xFFAB94C9, 0xFF9D87BC, 0xFF000000, 0xFF000000, 0xFFFFDEDB, 0xFFFFBEB9,
+            0xFF3D0004, 0xFF7E2A29, 0xFFFFFFFF,
+        ],
+        // Spec2026, light, Phone.
+        &[
+            0xFF2B74E2, 0xFF6277A3, 0xFF4F76C0, 0xFF727786, 0xFF727786, 0xFFCA4D48, 0xFFF9F9FF,
+            0xFF18243B, 0xFFF9F9FF, 0xFFCEDAF9, 0xFFF9F9FF, 0xFFFFFFFF, 0xFFF1F3FF, 0xFFE9EDFF,
+            0xFFE0E8FF, 0xFFD8E2FF, 0xFF18243B, 0xFFD8E2FF, 0xFF37435C, 0xFF535F7A, 0xFF6F7B96,
+            0xFF020E24, 0xFFC0C4D5, 0xFF000000, 0xFF000000, 0xFF00408C, 0xFF00408C, 0xFF003577,
+            0xFFCBDAFF, 0xFF2771DF, 0xFFFFFFFF, 0xFF2771DF, 0xFF0A64D2, 0xFFFFFFFF, 0xFFFFFFFF,
+            0xFF669BFF, 0xFF2D426B, 0xFF21375F, 0xFFCBDAFF, 0xFF6074A0, 0xFFFFFFFF, 0xFF6074A0,
+            0xFF536893, 0xFFFFFFFF, 0xFFFFFFFF, 0xFF0C4088, 0xFF003577, 0xFFCBDAFF, 0xFF4C73BD,
+            0xFFFFFFFF, 0xFF4C73BD, 0xFF3F67B0, 0xFFFFFFFF, 0xFFFFFFFF, 0xFF83181A, 0xFF68010A,
+            0xFFFFCECA, 0xFFC74B46, 0xFFFFFFFF,
+        ],
+        // Spec2026, dark, Phone.
+        &[
+            0xFF2B74E2, 0xFF6277A3, 0xFF4F76C0, 0xFF727786, 0xFF727786, 0xFFCA4D48, 0xFF090E19,
+            0xFFFFFFFF, 0xFF090E19, 0xFF090E19, 0xFF202C44, 0xFF000000, 0xFF0B1322, 0xFF10192B,
+            0xFF161F33, 0xFF1A263D, 0xFFFFFFFF, 0xFF1A263D, 0xFFADB9D7, 0xFF8893B0, 0xFF6A7591,
+            0xFFF9F9FF, 0xFF333845, 0xFF000000, 0xFF000000, 0xFF97B8FF, 0xFF97B8FF, 0xFF7FAAFF,
+            0xFF002A61, 0xFF689CFF, 0xFF000F2C, 0xFF689CFF, 0xFF4D8EFE, 0xFF000000, 0xFF000000,
+            0xFF0051AF, 0xFFA4B9E9, 0xFF96ABDA, 0xFF142B53, 0xFF6074A0, 0xFFFFFFFF, 0xFFB7CCFD,
+            0xFFA9BEEE, 0xFF00030E, 0xFF193057, 0xFF97B8FF, 0xFF84ABF9, 0xFF002A61, 0xFF779DEA,
+            0xFF000F2C, 0xFF779DEA, 0xFF6990DC, 0xFF000000, 0xFF000000, 0xFFFF9F97, 0xFFFF8880,
+            0xFF600008, 0xFFC74B46, 0xFFFFFFFF,
+        ],
+        // Spec2026, dark, Watch.
+        &[
+            0xFF2B74E2, 0xFF6277A3, 0xFF4F76C0, 0xFF727786, 0xFF727786, 0xFFCA4D48, 0xFF090E19,
+            0xFFFFFFFF, 0xFF090E19, 0xFF090E19, 0xFF202C44, 0xFF000000, 0xFF0B1322, 0xFF10192B,
+            0xFF161F33, 0xFF1A263D, 0xFFFFFFFF, 0xFF1A263D, 0xFFADB9D7, 0xFF8893B0, 0xFF6A7591,
+            0xFFF9F9FF, 0xFF333845, 0xFF000000, 0xFF000000, 0xFF97B8FF, 0xFF97B8FF, 0xFF7FAAFF,
+            0xFF002A61, 0xFF689CFF, 0xFF000F2C, 0xFF689CFF, 0xFF4D8EFE, 0xFF000000, 0xFF000000,
+            0xFF003678, 0xFFA4B9E9, 0xFF96ABDA, 0xFF142B53, 0xFF6074A0, 0xFFFFFFFF, 0xFFB7CCFD,
+            0xFFA9BEEE, 0xFF00030E, 0xFF193057, 0xFF97B8FF, 0xFF84ABF9, 0xFF002A61, 0xFF779DEA,
+            0xFF000F2C, 0xFF779DEA, 0xFF6990DC, 0xFF000000, 0xFF000000, 0xFFFF9F97, 0xFFFF8880,
+            0xFF600008, 0xFFC74B46, 0xFFFFFFFF,
+        ],
+    ];
+
+    const CONFIGS: [(bool, Platform); 3] = [
+        (false, Platform::Phone),
+        (true, Platform::Phone),
+        (true, Platform::Watch),
+    ];
+
+    /// TonalSpot for 2025; CMF for 2026, since TonalSpot falls back to 2025.
+    fn scheme(spec_version: SpecVersion, is_dark: bool, platform: Platform) -> DynamicScheme {
+        let source = Argb(0xFF4285F4);
+        let scheme = if spec_version == SpecVersion::Spec2025 {
+            SchemeTonalSpot::builder(source, is_dark, 0.5)
+                .spec_version(spec_version)
+                .platform(platform)
+                .build()
+        } else {
+            SchemeCmf::builder(source, is_dark, 0.5)
+                .spec_version(spec_version)
+                .platform(platform)
+                .build()
+        };
+        assert_eq!(scheme.spec_version, spec_version);
+        scheme
+    }
+
+    #[test]
+    fn test_shared_backgrounds_resolve_to_expected_roles() {
+        // The 2025 roles, resolved against both specs' surfaces.
+        let spec = ColorSpec2025::new();
+        for spec_version in [SpecVersion::Spec2025, SpecVersion::Spec2026] {
+            for (is_dark, platform) in CONFIGS {
+                let scheme = scheme(spec_version, is_dark, platform);
+                // Identify each shared background by the role it resolves to.
+                let background = |color: Arc<DynamicColor>| {
+                    let contrast = color.contrast.as_ref().expect("constraints");
+                    (contrast.background)(&scheme).map(|bg| bg.name.clone())
+                };
+                let highest = ColorSpecs::get(spec_version)
+                    .call()
+                    .highest_surface(&scheme);
+                let highest = Some(highest.name.clone());
+                let phone_highest = highest.clone().filter(|_| platform != Platform::Watch);
+                let context = format!("{spec_version:?} dark={is_dark} {platform:?}");
+
+                assert_eq!(background(spec.on_surface()), highest, "{context}");
+                assert_eq!(background(spec.primary()), highest, "{context}");
+                assert_eq!(background(spec.secondary()), highest, "{context}");
+                assert_eq!(
+                    background(spec.primary_dim().expect("2025 role")),
+                    Some("surface_container_high".to_string()),
+                    "{context}"
+                );
+                assert_eq!(
+                    background(spec.primary_container()),
+                    phone_highest,
+                    "{context}"
+                );
+                assert_eq!(
+                    background(spec.tertiary_fixed()),
+                    phone_highest,
+                    "{context}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_shared_backgrounds_resolve_unchanged() {
+        let configs = [SpecVersion::Spec2025, SpecVersion::Spec2026]
+            .into_iter()
+            .flat_map(|spec_version| CONFIGS.map(|config| (spec_version, config)));
+        for ((spec_version, (is_dark, platform)), expected) in configs.zip(BASELINE) {
+            let scheme = scheme(spec_version, is_dark, platform);
+            let colors = MaterialDynamicColors::new_with_spec(spec_version);
+            let roles: Vec<Arc<DynamicColor>> = colors
+                .all_dynamic_colors()
+                .iter()
+                .filter_map(|getter| getter())
+                .collect();
+            assert_eq!(roles.len(), expected.len(), "{spec_version:?}");
+            for (color, &argb) in roles.iter().zip(expected) {
+                assert_eq!(
+                    scheme.get_argb(color),
+                    Argb(argb),
+                    "{spec_version:?} {} dark={is_dark} {platform:?}",
+                    color.name,
+                );
+            }
+        }
+    }
+}
@@ -7,6 +7,7 @@ use crate::dynamic::contrast_curve::ContrastCurve;
 use crate::dynamic::dynamic_color::{ContrastConstraints, DynamicColor};
 use crate::dynamic::dynamic_scheme::DynamicScheme;
 use crate::dynamic::tone_delta_pair::{DeltaConstraint, ToneDeltaPair, TonePolarity};
+use crate::dynamic::tone_utils;
 use crate::dynamic::variant::Variant;
 use crate::hct::hct_color::Hct;
 use crate::palettes::tonal_palette::TonalPalette;
@@ -33,48 +34,6 @@ impl ColorSpec2026 {
 
     // --- Internal Helpers ---
 
-    fn find_best_tone_for_chroma(
-        hue: f64,
-        chroma: f64,
-        mut tone: f64,
-        by_decreasing_tone: bool,
-    ) -> f64 {
-        let mut answer = tone;
-        let mut best_candidate = Hct::new(hue, chroma, answer);
-        while best_candidate.chroma() < chroma {
-            if !(0.0..=100.0).contains(&tone) {
-                break;
-            }
-            tone += if by_decreasing_tone { -1.0 } else { 1.0 };
-            let new_candidate = Hct::new(hue, chroma, tone);
-            if best_candidate.chroma() < new_candidate.chroma() {
-                best_candidate = new_candidate;
-                answer = tone;
-            }
-        }
-        answer
-    }
-
-    fn t_max_c(
-        palette: &TonalPalette,
-        lower_bound: f64,
-        upper_bound: f64,
-        chroma_multiplier: f64,
-    ) -> f64 {
-        let answer = Self::find_best_tone_for_chroma(
-            palette.hue,
-            palette.chroma * chroma_multiplier,
-            100.0,
-            true,
-        );
-        answer.clamp(lower_bound, upper_bound)
-    }
-
-    fn t_min_c(palette: &TonalPalette, lower_bound: f64, upper_bound: f64) -> f64 {
-        let answer = Self::find_best_tone_for_chroma(palette.hue, palette.chroma, 0.0, false);
-        answer.clamp(lower_bound, upper_bound)
-    }
-
     fn get_contrast_curve(default_contrast: f64) -> ContrastCurve {
         match default_contrast {
             c if (c - 1.5).abs() < 1e-5 => ContrastCurve::new(1.5, 1.5, 3.0, 5.5),
@@ -784,9 +743,9 @@ impl ColorSpec for ColorSpec2026 {
                 true,
                 Some(Arc::new(|s| {
                     if s.is_dark {
-                        Self::t_min_c(&s.secondary_palette, 0.0, 100.0)
+                        tone_utils::t_min_c(&s.secondary_palette, 0.0, 100.0)
                     } else {
-                        Self::t_max_c(&s.secondary_palette, 0.0, 100.0, 1.0)
+                        tone_utils::t_max_c(&s.secondary_palette, 0.0, 100.0, 1.0)
                     }
                 })),
                 None,
@@ -842,9 +801,9 @@ impl ColorSpec for ColorSpec2026 {
                 true,
                 Some(Arc::new(|s| {
                     if s.is_dark {
-                        Self::t_min_c(&s.secondary_palette, 20.0, 49.0)
+                        tone_utils::t_min_c(&s.secondary_palette, 20.0, 49.0)
                     } else {
-                        Self::t_max_c(&s.secondary_palette, 61.0, 90.0, 1.0)
+                        tone_utils::t_max_c(&s.secondary_palette, 61.0, 90.0, 1.0)
                     }
                 })),
                 None,
@@ -1295,7 +1254,7 @@ impl ColorSpec for ColorSpec2026 {
                 Arc::new(|s| s.error_palette.clone()),
                 true,
                 Some(Arc::new(|s| {
-                    Self::t_max_c(&s.error_palette, 0.0, 100.0, 1.0)
+                    tone_utils::t_max_c(&s.error_palette, 0.0, 100.0, 1.0)
                 })),
                 None,
                 None,
@@ -1344,9 +1303,9 @@ impl ColorSpec for ColorSpec2026 {
                 true,
                 Some(Arc::new(|s| {
                     if s.is_dark {
-                        Self::t_min_c(&s.error_palette, 0.0, 100.0)
+                        tone_utils::t_min_c(&s.error_palette, 0.0, 100.0)
                     } else {
-                        Self::t_max_c(&s.error_palette, 0.0, 100.0, 1.0)
+                        tone_utils::t_max_c(&s.error_palette, 0.0, 100.0, 1.0)
                     }
                 })),
                 None,
@@ -4,6 +4,19 @@ use crate::utils::math_utils::MathUtils;
 ///
 /// Usually represents the contrast requirements for a dynamic color on its background. The four
 /// values correspond to values for contrast levels -1.0, 0.0, 0.5, and 1.0, respectively.
+///
+/// Custom curves can be attached to a bespoke
+/// [`DynamicColor`](crate::dynamic::dynamic_color::DynamicColor) to control how much contrast it
+/// keeps against its background at each contrast level:
+///
+/// ```
+/// use material_color_utils::dynamic::contrast_curve::ContrastCurve;
+///
+/// let curve = ContrastCurve::new(3.0, 4.5, 7.0, 11.0);
+/// assert_eq!(curve.get(0.0), 4.5);
+/// assert_eq!(curve.get(0.25), 5.75);
+/// assert_eq!(ContrastCurve::constant(3.0).get(1.0), 3.0);
+/// ```
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct ContrastCurve {
     /// Value for contrast level -1.0
@@ -17,6 +30,7 @@ pub struct ContrastCurve {
 }
 
 impl ContrastCurve {
+    /// Creates a curve from its values at contrast levels -1.0, 0.0, 0.5 and 1.0.
     #[must_use]
     pub const fn new(low: f64, normal: f64, medium: f64, high: f64) -> Self {
         Self {
@@ -27,8 +41,17 @@ impl ContrastCurve {
         }
     }
 
+    /// Creates a curve that has the same value at every contrast level.
+    #[must_use]
+    pub const fn constant(value: f64) -> Self {
+        Self::new(value, value, value, value)
+    }
+
     /// Returns the value at a given contrast level.
     ///
+    /// Between control points the value is interpolated linearly. Levels below -1.0 return `low`
+    /// and levels above 1.0 return `high`; a NaN level is treated as 0.0 and returns `normal`.
+    ///
     /// # Arguments
     /// * `contrast_level` - The contrast level. 0.0 is the default (normal); -1.0 is the lowest; 1.0
     ///   is the highest.
@@ -38,6 +61,7 @@ impl ContrastCurve {
     #[must_use]
     pub fn get(&self, contrast_level: f64) -> f64 {
         match contrast_level {
+            x if x.is_nan() => self.normal,
             x if x <= -1.0 => self.low,
             x if x < 0.0 => MathUtils::lerp(self.low, self.normal, x + 1.0),
             x if x < 0.5 => MathUtils::lerp(self.normal, self.medium, x / 0.5),
@@ -71,4 +95,39 @@ mod tests {
         assert!((curve.get(0.25) - 5.75).abs() < 1e-9);
         assert!((curve.get(0.75) - 9.0).abs() < 1e-9);
     }
+
+    #[test]
+    fn test_contrast_curve_is_piecewise_linear() {
+        let curve = ContrastCurve::new(1.0, 3.0, 4.0, 8.0);
+        let points = [(-1.0, 1.0), (0.0, 3.0), (0.5, 4.0), (1.0, 8.0)];
+        for pair in points.windows(2) {
+            let ((x0, y0), (x1, y1)) = (pair[0], pair[1]);
+            for step in 0..=10 {
+                let t = f64::from(step) / 10.0;
+                let level = x0 + (x1 - x0) * t;
+                let expected = y0 + (y1 - y0) * t;
+                assert!(
+                    (curve.get(level) - expected).abs() < 1e-9,
+                    "level {level}: {} != {expected}",
+                    curve.get(level)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_contrast_curve_outside_range() {
+        let curve = ContrastCurve::new(1.0, 3.0, 4.0, 8.0);
+        assert_eq!(curve.get(f64::NEG_INFINITY), 1.0);
+        assert_eq!(curve.get(f64::INFINITY), 8.0);
+        assert_eq!(curve.get(f64::NAN), 3.0);
+    }
+
+    #[test]
+    fn test_contrast_curve_constant() {
+        let curve = ContrastCurve::constant(4.5);
+        for level in [-2.0, -1.0, -0.3, 0.0, 0.5, 0.9, 1.0, 3.0] {
+            assert_eq!(curve.get(level), 4.5);
+        }
+    }
 }
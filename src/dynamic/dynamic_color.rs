@@ -52,6 +52,24 @@ impl Debug for DynamicColor {
 }
 
 impl DynamicColor {
+    /// Starts a [`DynamicColorBuilder`] for a color named `name`, as a named alternative to the
+    /// positional [`DynamicColor::new`].
+    #[must_use]
+    pub fn builder(name: impl Into<String>) -> DynamicColorBuilder {
+        DynamicColorBuilder {
+            name: name.into(),
+            palette: None,
+            is_background: false,
+            tone: None,
+            chroma_multiplier: None,
+            tone_delta_pair: None,
+            opacity: None,
+            background: None,
+            second_background: None,
+            contrast_curve: None,
+        }
+    }
+
     pub fn new(
         name: String,
         palette: DynamicColorFunction<TonalPalette>,
@@ -89,6 +107,19 @@ impl DynamicColor {
         }
     }
 
+    /// The role name, e.g. `"on_primary"`.
+    #[must_use]
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Whether this is one of the `*_fixed_dim` roles. From the 2025 spec on, background colors
+    /// are kept out of the T50-T60 range, except for these.
+    #[must_use]
+    pub fn is_fixed_dim(&self) -> bool {
+        self.name.ends_with("_fixed_dim")
+    }
+
     #[must_use]
     pub fn get_argb(&self, scheme: &DynamicScheme) -> Argb {
         scheme.get_argb(self)
@@ -104,6 +135,23 @@ impl DynamicColor {
         scheme.get_tone(self)
     }
 
+    /// The WCAG contrast ratio between this color and its background in `scheme`, or `None` if the
+    /// color has no background.
+    #[must_use]
+    pub fn contrast_against_background(&self, scheme: &DynamicScheme) -> Option<f64> {
+        let background = (self.contrast.as_ref()?.background)(scheme)?;
+        Some(Contrast::ratio_of_tones(
+            scheme.get_tone(self),
+            scheme.get_tone(&background),
+        ))
+    }
+
+    /// The opacity of this color in `scheme`, from 0.0 to 1.0, or `None` if the color is opaque.
+    #[must_use]
+    pub fn get_opacity(&self, scheme: &DynamicScheme) -> Option<f64> {
+        self.opacity.as_ref().and_then(|opacity| opacity(scheme))
+    }
+
     /// Create a `DynamicColor` from an ARGB hex code.
     #[must_use]
     pub fn from_argb(name: &str, argb: Argb) -> Self {
@@ -275,6 +323,24 @@ impl DynamicColor {
         );
     }
 
+    /// Solves for the tone of a foreground that reaches `ratio` against a background of
+    /// `bg_tone`, as the specs do for every color with a contrast curve.
+    ///
+    /// Lighter and darker candidates are both tried. Backgrounds that
+    /// [prefer a light foreground](Self::tone_prefers_light_foreground) pick the lighter one unless
+    /// only the darker one reaches the ratio; other backgrounds pick the darker one unless only the
+    /// lighter one reaches it. If neither can, the one with more contrast wins.
+    ///
+    /// ```
+    /// use material_color_utils::dynamic::dynamic_color::DynamicColor;
+    ///
+    /// // White on T50 falls just short of 4.5:1, so a near-black tone is used instead.
+    /// let tone = DynamicColor::foreground_tone(50.0, 4.5);
+    /// assert!((tone - 1.444_032_921_810_708).abs() < 1e-9);
+    ///
+    /// // A dark background gets a light foreground.
+    /// assert!(DynamicColor::foreground_tone(10.0, 4.5) > 50.0);
+    /// ```
     #[must_use]
     pub fn foreground_tone(bg_tone: f64, ratio: f64) -> f64 {
         let lighter_tone = Contrast::lighter_unsafe(bg_tone, ratio);
@@ -299,6 +365,8 @@ impl DynamicColor {
         }
     }
 
+    /// Adjusts a background tone so that a light foreground can reach 4.5:1 on it: tones that
+    /// prefer a light foreground but don't allow one are lowered to T49.
     #[must_use]
     pub fn enable_light_foreground(tone: f64) -> f64 {
         if Self::tone_prefers_light_foreground(tone) && !Self::tone_allows_light_foreground(tone) {
@@ -308,7 +376,36 @@ impl DynamicColor {
         }
     }
 
+    /// Moves a background tone out of the T50-T60 "dead zone", where neither black nor white
+    /// text reaches good contrast: to T49 when `prefer_darker` is set, to T60 otherwise. Tones
+    /// outside the zone are returned as-is.
+    ///
+    /// ```
+    /// use material_color_utils::dynamic::dynamic_color::DynamicColor;
+    ///
+    /// assert_eq!(DynamicColor::avoid_dead_zone(55.0, true), 49.0);
+    /// assert_eq!(DynamicColor::avoid_dead_zone(55.0, false), 60.0);
+    /// assert_eq!(DynamicColor::avoid_dead_zone(60.0, true), 60.0);
+    /// ```
+    #[must_use]
+    pub fn avoid_dead_zone(tone: f64, prefer_darker: bool) -> f64 {
+        if !(50.0..60.0).contains(&tone) {
+            tone
+        } else if prefer_darker {
+            49.0
+        } else {
+            60.0
+        }
+    }
+
     /// People prefer white foregrounds on ~T60-70.
+    ///
+    /// ```
+    /// use material_color_utils::dynamic::dynamic_color::DynamicColor;
+    ///
+    /// assert!(DynamicColor::tone_prefers_light_foreground(59.4));
+    /// assert!(!DynamicColor::tone_prefers_light_foreground(59.5));
+    /// ```
     #[must_use]
     pub fn tone_prefers_light_foreground(tone: f64) -> bool {
         tone.round() < 60.0
@@ -321,6 +418,131 @@ impl DynamicColor {
     }
 }
 
+/// Builder for [`DynamicColor`], created with [`DynamicColor::builder`].
+///
+/// Only the palette is required. Options left unset behave as `None` does in
+/// [`DynamicColor::new`]; in particular, without a tone the color takes its background's tone.
+pub struct DynamicColorBuilder {
+    name: String,
+    palette: Option<DynamicColorFunction<TonalPalette>>,
+    is_background: bool,
+    tone: Option<DynamicColorFunction<f64>>,
+    chroma_multiplier: Option<DynamicColorFunction<f64>>,
+    tone_delta_pair: Option<DynamicColorFunction<Option<ToneDeltaPair>>>,
+    opacity: Option<DynamicColorFunction<Option<f64>>>,
+    background: Option<DynamicColorFunction<Option<Arc<DynamicColor>>>>,
+    second_background: Option<DynamicColorFunction<Option<Arc<DynamicColor>>>>,
+    contrast_curve: Option<DynamicColorFunction<Option<ContrastCurve>>>,
+}
+
+impl DynamicColorBuilder {
+    #[must_use]
+    pub fn palette(
+        mut self,
+        palette: impl Fn(&DynamicScheme) -> TonalPalette + Send + Sync + 'static,
+    ) -> Self {
+        self.palette = Some(Arc::new(palette));
+        self
+    }
+
+    #[must_use]
+    pub const fn is_background(mut self, is_background: bool) -> Self {
+        self.is_background = is_background;
+        self
+    }
+
+    #[must_use]
+    pub fn tone(mut self, tone: impl Fn(&DynamicScheme) -> f64 + Send + Sync + 'static) -> Self {
+        self.tone = Some(Arc::new(tone));
+        self
+    }
+
+    #[must_use]
+    pub fn chroma_multiplier(
+        mut self,
+        chroma_multiplier: impl Fn(&DynamicScheme) -> f64 + Send + Sync + 'static,
+    ) -> Self {
+        self.chroma_multiplier = Some(Arc::new(chroma_multiplier));
+        self
+    }
+
+    #[must_use]
+    pub fn tone_delta_pair(
+        mut self,
+        tone_delta_pair: impl Fn(&DynamicScheme) -> Option<ToneDeltaPair> + Send + Sync + 'static,
+    ) -> Self {
+        self.tone_delta_pair = Some(Arc::new(tone_delta_pair));
+        self
+    }
+
+    #[must_use]
+    pub fn opacity(
+        mut self,
+        opacity: impl Fn(&DynamicScheme) -> Option<f64> + Send + Sync + 'static,
+    ) -> Self {
+        self.opacity = Some(Arc::new(opacity));
+        self
+    }
+
+    #[must_use]
+    pub fn background(
+        mut self,
+        background: impl Fn(&DynamicScheme) -> Option<Arc<DynamicColor>> + Send + Sync + 'static,
+    ) -> Self {
+        self.background = Some(Arc::new(background));
+        self
+    }
+
+    #[must_use]
+    pub fn second_background(
+        mut self,
+        second_background: impl Fn(&DynamicScheme) -> Option<Arc<DynamicColor>> + Send + Sync + 'static,
+    ) -> Self {
+        self.second_background = Some(Arc::new(second_background));
+        self
+    }
+
+    #[must_use]
+    pub fn contrast_curve(
+        mut self,
+        contrast_curve: impl Fn(&DynamicScheme) -> Option<ContrastCurve> + Send + Sync + 'static,
+    ) -> Self {
+        self.contrast_curve = Some(Arc::new(contrast_curve));
+        self
+    }
+
+    /// Builds the color. Contrast constraints are only attached when a background or contrast
+    /// curve was given.
+    ///
+    /// # Panics
+    /// Panics if no palette was set.
+    #[must_use]
+    pub fn build(self) -> DynamicColor {
+        let palette = self
+            .palette
+            .unwrap_or_else(|| panic!("DynamicColor {} was built without a palette", self.name));
+        let contrast = if self.background.is_some() || self.contrast_curve.is_some() {
+            Some(ContrastConstraints {
+                background: self.background.unwrap_or_else(|| Arc::new(|_| None)),
+                contrast_curve: self.contrast_curve.unwrap_or_else(|| Arc::new(|_| None)),
+                second_background: self.second_background,
+            })
+        } else {
+            None
+        };
+        DynamicColor::new(
+            self.name,
+            palette,
+            self.is_background,
+            self.tone,
+            self.chroma_multiplier,
+            self.tone_delta_pair,
+            self.opacity,
+            contrast,
+        )
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -352,4 +574,131 @@ mod tests {
         assert!(DynamicColor::tone_allows_light_foreground(49.0));
         assert!(!DynamicColor::tone_allows_light_foreground(50.0));
     }
+
+    #[test]
+    fn test_get_opacity() {
+        let scheme = crate::scheme::SchemeTonalSpot::builder(Argb(0xff4285f4), false, 0.0).build();
+        let colors = crate::dynamic::material_dynamic_colors::MaterialDynamicColors::new();
+        assert_eq!(colors.primary().get_opacity(&scheme), None);
+        assert_eq!(colors.scrim().get_opacity(&scheme), None);
+
+        let scrim = colors.scrim();
+        let overlay = DynamicColor::new(
+            "scrim_overlay".to_string(),
+            Arc::clone(&scrim.palette),
+            false,
+            Some(Arc::clone(&scrim.tone)),
+            None,
+            None,
+            Some(Arc::new(|_| Some(0.32))),
+            None,
+        );
+        assert_eq!(overlay.get_opacity(&scheme), Some(0.32));
+
+        let argb = scheme.get_argb(&overlay);
+        assert_eq!(argb.alpha(), 82);
+        let (opaque, opacity) = scheme.get_argb_with_opacity(&overlay);
+        assert!(opaque.is_opaque());
+        assert_eq!(opaque.0 & 0x00ffffff, argb.0 & 0x00ffffff);
+        assert_eq!(opacity, Some(0.32));
+    }
+
+    #[test]
+    fn test_contrast_against_background() {
+        let scheme = crate::scheme::SchemeTonalSpot::builder(Argb(0xff4285f4), false, 0.0).build();
+        let colors = crate::dynamic::material_dynamic_colors::MaterialDynamicColors::new();
+
+        let on_surface = colors.on_surface();
+        let constraints = on_surface
+            .contrast
+            .as_ref()
+            .expect("on_surface has a background");
+        let curve = (constraints.contrast_curve)(&scheme).expect("on_surface has a contrast curve");
+        let ratio = on_surface
+            .contrast_against_background(&scheme)
+            .expect("on_surface has a background");
+        assert!(ratio >= curve.get(0.0) - 0.05, "{ratio}");
+
+        assert_eq!(colors.surface().contrast_against_background(&scheme), None);
+    }
+
+    #[test]
+    fn test_builder_matches_new() {
+        let colors = crate::dynamic::material_dynamic_colors::MaterialDynamicColors::new();
+        let built = DynamicColor::builder("brand")
+            .palette(|s| s.primary_palette.clone())
+            .tone(|s| if s.is_dark { 80.0 } else { 40.0 })
+            .background(move |_| Some(colors.surface()))
+            .contrast_curve(|_| Some(ContrastCurve::new(3.0, 4.5, 7.0, 11.0)))
+            .build();
+
+        let colors = crate::dynamic::material_dynamic_colors::MaterialDynamicColors::new();
+        let positional = DynamicColor::new(
+            "brand".to_string(),
+            Arc::new(|s| s.primary_palette.clone()),
+            false,
+            Some(Arc::new(|s| if s.is_dark { 80.0 } else { 40.0 })),
+            None,
+            None,
+            None,
+            Some(ContrastConstraints {
+                background: Arc::new(move |_| Some(colors.surface())),
+                contrast_curve: Arc::new(|_| Some(ContrastCurve::new(3.0, 4.5, 7.0, 11.0))),
+                second_background: None,
+            }),
+        );
+
+        assert_eq!(built.name, positional.name);
+        assert_eq!(built.is_background, positional.is_background);
+        for (is_dark, contrast) in [(false, 0.0), (true, 0.0), (false, 1.0), (true, -1.0)] {
+            // Schemes cache resolved colors by name, so each color gets its own scheme.
+            let scheme = || {
+                crate::scheme::SchemeTonalSpot::builder(Argb(0xff4285f4), is_dark, contrast).build()
+            };
+            let (for_built, for_positional) = (scheme(), scheme());
+            assert_eq!(
+                for_built.get_argb(&built),
+                for_positional.get_argb(&positional)
+            );
+            assert_eq!(
+                built.contrast_against_background(&for_built),
+                positional.contrast_against_background(&for_positional)
+            );
+        }
+    }
+
+    #[test]
+    fn test_builder_without_contrast() {
+        let color = DynamicColor::builder("plain")
+            .palette(|s| s.secondary_palette.clone())
+            .tone(|_| 50.0)
+            .is_background(true)
+            .build();
+        assert!(color.is_background);
+        assert!(color.contrast.is_none());
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_avoid_dead_zone() {
+        assert_eq!(DynamicColor::avoid_dead_zone(55.0, true), 49.0);
+        assert_eq!(DynamicColor::avoid_dead_zone(55.0, false), 60.0);
+        assert_eq!(DynamicColor::avoid_dead_zone(50.0, false), 60.0);
+        assert_eq!(DynamicColor::avoid_dead_zone(59.9, true), 49.0);
+        assert_eq!(DynamicColor::avoid_dead_zone(49.9, false), 49.9);
+        assert_eq!(DynamicColor::avoid_dead_zone(60.0, true), 60.0);
+    }
+
+    #[test]
+    fn test_name_and_is_fixed_dim() {
+        let colors = crate::dynamic::material_dynamic_colors::MaterialDynamicColors::new();
+        let fixed_dim = colors.primary_fixed_dim();
+        assert_eq!(fixed_dim.name(), "primary_fixed_dim");
+        assert!(fixed_dim.is_fixed_dim());
+        assert!(colors.tertiary_fixed_dim().is_fixed_dim());
+
+        assert!(!colors.primary_fixed().is_fixed_dim());
+        assert!(!colors.on_primary_fixed_variant().is_fixed_dim());
+        assert!(!colors.surface_dim().is_fixed_dim());
+    }
 }
@@ -1,31 +1,60 @@
+use crate::contrast::ContrastPreset;
 use crate::dynamic::color_spec::{Platform, SpecVersion};
 use crate::dynamic::dynamic_color::DynamicColor;
 use crate::dynamic::material_dynamic_colors::MaterialDynamicColors;
+use crate::dynamic::resolved_scheme::{ResolvedRole, ResolvedScheme};
 use crate::dynamic::variant::Variant;
 use crate::hct::hct_color::Hct;
 use crate::palettes::tonal_palette::TonalPalette;
+use crate::scheme::{
+    SchemeCmf, SchemeContent, SchemeExpressive, SchemeFidelity, SchemeFruitSalad, SchemeMonochrome,
+    SchemeNeutral, SchemeRainbow, SchemeTonalSpot, SchemeVibrant,
+};
 use crate::utils::color_utils::Argb;
 use crate::utils::math_utils::MathUtils;
-use std::sync::OnceLock;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, OnceLock};
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
+/// The palettes and parameters a set of [`DynamicColor`] roles resolves against.
+///
+/// Cloning is cheap enough to fork a scheme per preview: the color spec is looked up from
+/// `spec_version` rather than stored, and the palettes share their tone caches through an `Arc`.
+/// The resolved-role caches are copied, so a clone starts out with whatever the original has
+/// already resolved and the two fill their caches independently afterwards.
 #[derive(Debug, Clone)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(from = "DynamicSchemeSurrogate"))]
 pub struct DynamicScheme {
     pub source_color_hct_list: Vec<Hct>,
     pub variant: Variant,
     pub is_dark: bool,
+    /// Contrast level in `-1.0..=1.0`: 0.0 is the default, -1.0 the lowest and 1.0 the
+    /// highest contrast. Values outside this range are clamped on construction.
     pub contrast_level: f64,
+    /// The contrast level as passed to the constructor, before clamping. Schemes serialized
+    /// without it deserialize with the clamped `contrast_level` here.
+    pub contrast_level_raw: f64,
     pub platform: Platform,
     pub spec_version: SpecVersion,
+    /// The spec version as passed to the constructor, before falling back to one the variant
+    /// supports. Schemes serialized without it deserialize with `spec_version` here.
+    pub requested_spec_version: SpecVersion,
     pub primary_palette: TonalPalette,
     pub secondary_palette: TonalPalette,
     pub tertiary_palette: TonalPalette,
     pub neutral_palette: TonalPalette,
     pub neutral_variant_palette: TonalPalette,
     pub error_palette: TonalPalette,
+    /// Fixed colors for individual roles, keyed by role name. Set through
+    /// [`DynamicScheme::set_role_override`] so the caches are cleared along with it.
+    role_overrides: HashMap<String, Argb>,
+    /// Whether the 2021 spec lightens disliked (dark yellow-green) tertiary container tones in
+    /// the content and fidelity variants. Defaults to `true`. See
+    /// [`DynamicScheme::set_fix_disliked_colors`].
+    pub fix_disliked_colors: bool,
 
     #[cfg_attr(feature = "serde", serde(skip))]
     pub argb_cache: papaya::HashMap<String, Argb>,
@@ -51,6 +80,8 @@ impl PartialEq for DynamicScheme {
             && self.neutral_palette == other.neutral_palette
             && self.neutral_variant_palette == other.neutral_variant_palette
             && self.error_palette == other.error_palette
+            && self.role_overrides == other.role_overrides
+            && self.fix_disliked_colors == other.fix_disliked_colors
     }
 }
 
@@ -64,6 +95,7 @@ impl std::hash::Hash for DynamicScheme {
         self.contrast_level.to_bits().hash(state);
         self.platform.hash(state);
         self.spec_version.hash(state);
+        self.fix_disliked_colors.hash(state);
     }
 }
 
@@ -116,24 +148,150 @@ impl DynamicScheme {
             source_color_hct_list: vec![source_color_hct],
             variant,
             is_dark,
-            contrast_level,
+            contrast_level: contrast_level.clamp(-1.0, 1.0),
+            contrast_level_raw: contrast_level,
             platform,
             spec_version: Self::maybe_fallback_spec_version(spec_version, variant),
+            requested_spec_version: spec_version,
             primary_palette,
             secondary_palette,
             tertiary_palette,
             neutral_palette,
             neutral_variant_palette,
             error_palette,
+            role_overrides: HashMap::new(),
+            fix_disliked_colors: true,
             argb_cache: papaya::HashMap::new(),
             tone_cache: papaya::HashMap::new(),
             hct_cache: papaya::HashMap::new(),
         }
     }
 
+    /// Creates a scheme of the given variant from one or more source colors.
+    ///
+    /// The first color is the main source color, all colors end up in `source_color_hct_list`.
+    /// Some specs use the additional colors, for example `Variant::Cmf` on `Spec2026` derives its
+    /// tertiary roles from the second color. With a single color this is the same as calling the
+    /// variant's scheme builder directly.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `source_colors` is empty.
+    #[must_use]
+    pub fn from_source_colors(
+        source_colors: &[Hct],
+        variant: Variant,
+        is_dark: bool,
+        contrast_level: f64,
+        platform: Platform,
+        spec_version: SpecVersion,
+    ) -> Self {
+        let (&source_color, additional_colors) = source_colors
+            .split_first()
+            .expect("`source_colors` must contain at least one color");
+        let additional_colors = additional_colors.to_vec();
+        match variant {
+            Variant::TonalSpot => SchemeTonalSpot::builder(source_color, is_dark, contrast_level)
+                .additional_colors(additional_colors)
+                .spec_version(spec_version)
+                .platform(platform)
+                .build(),
+            Variant::Vibrant => SchemeVibrant::builder(source_color, is_dark, contrast_level)
+                .additional_colors(additional_colors)
+                .spec_version(spec_version)
+                .platform(platform)
+                .build(),
+            Variant::Expressive => SchemeExpressive::builder(source_color, is_dark, contrast_level)
+                .additional_colors(additional_colors)
+                .spec_version(spec_version)
+                .platform(platform)
+                .build(),
+            Variant::Content => SchemeContent::builder(source_color, is_dark, contrast_level)
+                .additional_colors(additional_colors)
+                .spec_version(spec_version)
+                .platform(platform)
+                .build(),
+            Variant::Fidelity => SchemeFidelity::builder(source_color, is_dark, contrast_level)
+                .additional_colors(additional_colors)
+                .spec_version(spec_version)
+                .platform(platform)
+                .build(),
+            Variant::Monochrome => SchemeMonochrome::builder(source_color, is_dark, contrast_level)
+                .additional_colors(additional_colors)
+                .spec_version(spec_version)
+                .platform(platform)
+                .build(),
+            Variant::Neutral => SchemeNeutral::builder(source_color, is_dark, contrast_level)
+                .additional_colors(additional_colors)
+                .spec_version(spec_version)
+                .platform(platform)
+                .build(),
+            Variant::Rainbow => SchemeRainbow::builder(source_color, is_dark, contrast_level)
+                .additional_colors(additional_colors)
+                .spec_version(spec_version)
+                .platform(platform)
+                .build(),
+            Variant::FruitSalad => SchemeFruitSalad::builder(source_color, is_dark, contrast_level)
+                .additional_colors(additional_colors)
+                .spec_version(spec_version)
+                .platform(platform)
+                .build(),
+            Variant::Cmf => SchemeCmf::builder(source_color, is_dark, contrast_level)
+                .additional_colors(additional_colors)
+                .spec_version(spec_version)
+                .platform(platform)
+                .build(),
+        }
+    }
+
+    /// Creates a scheme of the given variant at a named contrast level, using the latest spec
+    /// version. See [`DynamicScheme::from_source_colors`] to pick the spec version or pass a raw
+    /// contrast level.
+    #[must_use]
+    pub fn from_preset(
+        source_color: Hct,
+        variant: Variant,
+        is_dark: bool,
+        preset: ContrastPreset,
+        platform: Platform,
+    ) -> Self {
+        Self::from_source_colors(
+            &[source_color],
+            variant,
+            is_dark,
+            preset.level(),
+            platform,
+            SpecVersion::latest(),
+        )
+    }
+
+    /// A copy of this scheme at another contrast level, reusing its palettes instead of
+    /// rebuilding them from the source color.
+    #[must_use]
+    pub fn at_contrast(&self, contrast_level: f64) -> Self {
+        Self::from_scheme_with_contrast(self, self.is_dark, contrast_level)
+    }
+
+    /// A copy of this scheme with another variant. The palettes are rebuilt from the same source
+    /// colors, while brightness, contrast, platform, spec version and role overrides are kept.
+    #[must_use]
+    pub fn with_variant(&self, variant: Variant) -> Self {
+        let mut scheme = Self::from_source_colors(
+            &self.source_color_hct_list,
+            variant,
+            self.is_dark,
+            self.contrast_level_raw,
+            self.platform,
+            self.requested_spec_version,
+        );
+        scheme.role_overrides.clone_from(&self.role_overrides);
+        scheme.fix_disliked_colors = self.fix_disliked_colors;
+        scheme
+    }
+
     #[must_use]
     pub fn from_scheme(other: &Self, is_dark: bool) -> Self {
-        Self::from_scheme_with_contrast(other, is_dark, other.contrast_level)
+        Self::from_scheme_with_contrast(other, is_dark, other.contrast_level_raw)
     }
 
     #[must_use]
@@ -142,15 +300,19 @@ impl DynamicScheme {
             source_color_hct_list: other.source_color_hct_list.clone(),
             variant: other.variant,
             is_dark,
-            contrast_level,
+            contrast_level: contrast_level.clamp(-1.0, 1.0),
+            contrast_level_raw: contrast_level,
             platform: other.platform,
             spec_version: other.spec_version,
+            requested_spec_version: other.requested_spec_version,
             primary_palette: other.primary_palette.clone(),
             secondary_palette: other.secondary_palette.clone(),
             tertiary_palette: other.tertiary_palette.clone(),
             neutral_palette: other.neutral_palette.clone(),
             neutral_variant_palette: other.neutral_variant_palette.clone(),
             error_palette: other.error_palette.clone(),
+            role_overrides: other.role_overrides.clone(),
+            fix_disliked_colors: other.fix_disliked_colors,
             argb_cache: papaya::HashMap::new(),
             tone_cache: papaya::HashMap::new(),
             hct_cache: papaya::HashMap::new(),
@@ -163,13 +325,171 @@ impl DynamicScheme {
         &self.source_color_hct_list[0]
     }
 
+    /// Returns the primary source color, i.e. the seed the scheme was built from, in ARGB.
     #[must_use]
     pub fn source_color_argb(&self) -> Argb {
         self.source_color_hct().to_argb()
     }
 
+    #[must_use]
+    pub const fn variant(&self) -> Variant {
+        self.variant
+    }
+
+    #[must_use]
+    pub const fn is_dark(&self) -> bool {
+        self.is_dark
+    }
+
+    #[must_use]
+    pub const fn platform(&self) -> Platform {
+        self.platform
+    }
+
+    /// The contrast level in use, after clamping. See [`DynamicScheme::contrast_level_raw`] for
+    /// the requested value.
+    #[must_use]
+    pub const fn contrast_level(&self) -> f64 {
+        self.contrast_level
+    }
+
+    /// Forces the role named `role` (e.g. `"error"`) to resolve to exactly `argb`.
+    ///
+    /// Other roles still derive from the palettes, and roles that contrast against the overridden
+    /// one adapt to the new color. Clears previously resolved colors.
+    pub fn set_role_override(&mut self, role: &str, argb: Argb) -> &mut Self {
+        self.role_overrides.insert(role.to_string(), argb);
+        self.argb_cache.pin().clear();
+        self.tone_cache.pin().clear();
+        self.hct_cache.pin().clear();
+        self
+    }
+
+    /// The fixed colors set with [`DynamicScheme::set_role_override`], keyed by role name.
+    #[must_use]
+    pub const fn role_overrides(&self) -> &HashMap<String, Argb> {
+        &self.role_overrides
+    }
+
+    /// Turns the disliked-color fix on or off. With it off, a fidelity or content scheme keeps the
+    /// tertiary container at the source color's tone even when that lands on a disliked dark
+    /// yellow-green. The tertiary palette itself is still picked when the scheme is built. Clears
+    /// previously resolved colors.
+    pub fn set_fix_disliked_colors(&mut self, fix_disliked_colors: bool) -> &mut Self {
+        self.fix_disliked_colors = fix_disliked_colors;
+        self.argb_cache.pin().clear();
+        self.tone_cache.pin().clear();
+        self.hct_cache.pin().clear();
+        self
+    }
+
+    /// Resolves every role in [`MaterialDynamicColors`] that exists for this scheme's spec version,
+    /// keyed by role name (e.g. `"on_primary"`) and sorted by name.
+    ///
+    /// Handy as template context when rendering a scheme into an arbitrary output format.
+    #[must_use]
+    pub fn to_map(&self) -> BTreeMap<String, Argb> {
+        MaterialDynamicColors::new_with_spec(self.spec_version)
+            .all_dynamic_colors()
+            .iter()
+            .filter_map(|getter| getter())
+            .map(|color| (color.name.clone(), self.get_argb(&color)))
+            .collect()
+    }
+
+    /// Resolves every role in [`MaterialDynamicColors`] that exists for this scheme's spec version.
+    ///
+    /// Roles reference each other (`on_surface` needs `surface_bright` and `surface_dim`, most
+    /// roles need the highest surface), but every tone and HCT lands in this scheme's caches the
+    /// first time it's resolved, so each role is solved exactly once no matter how often it's
+    /// referenced. Later `get_*` calls on this scheme are cache hits.
+    #[must_use]
+    pub fn resolve_all(&self) -> ResolvedScheme {
+        let roles = MaterialDynamicColors::new_with_spec(self.spec_version)
+            .all_dynamic_colors()
+            .iter()
+            .filter_map(|getter| getter())
+            .map(|color| {
+                let role = ResolvedRole {
+                    argb: self.get_argb(&color),
+                    tone: self.get_tone(&color),
+                };
+                (color.name.clone(), role)
+            })
+            .collect();
+        ResolvedScheme { roles }
+    }
+
+    /// Checks that every role in [`MaterialDynamicColors`] can be resolved on this scheme.
+    ///
+    /// Resolving a tone follows the role's `background`, `second_background` and, from the 2025
+    /// spec on, the other role of its `tone_delta_pair`. This walks those references and reports
+    /// every chain that leads back to a role already on it, including a role referencing itself.
+    ///
+    /// # Errors
+    /// Returns one message per role that is part of a cycle, e.g. `"primary: primary -> primary"`.
+    pub fn validate(&self) -> Result<(), Vec<String>> {
+        let problems: Vec<String> = dynamic_colors()
+            .all_dynamic_colors()
+            .iter()
+            .filter_map(|getter| getter())
+            .filter_map(|color| {
+                self.find_cycle(&color, &mut Vec::new())
+                    .map(|cycle| format!("{}: {}", color.name, cycle.join(" -> ")))
+            })
+            .collect();
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(problems)
+        }
+    }
+
+    fn find_cycle(&self, color: &DynamicColor, path: &mut Vec<String>) -> Option<Vec<String>> {
+        if let Some(start) = path.iter().position(|name| *name == color.name) {
+            let mut cycle = path[start..].to_vec();
+            cycle.push(color.name.clone());
+            return Some(cycle);
+        }
+        path.push(color.name.clone());
+        let cycle = self
+            .tone_dependencies(color)
+            .iter()
+            .find_map(|dependency| self.find_cycle(dependency, path));
+        path.pop();
+        cycle
+    }
+
+    /// The colors whose tones are resolved while resolving the tone of `color`.
+    fn tone_dependencies(&self, color: &DynamicColor) -> Vec<Arc<DynamicColor>> {
+        let pair = color.tone_delta_pair.as_ref().and_then(|f| f(self));
+        let mut dependencies = Vec::new();
+        if let Some(constraints) = &color.contrast {
+            dependencies.extend((constraints.background)(self));
+            if pair.is_none()
+                && let Some(second_background) = &constraints.second_background
+            {
+                dependencies.extend(second_background(self));
+            }
+        }
+        if let Some(pair) = pair
+            && self.spec_version != SpecVersion::Spec2021
+        {
+            dependencies.push(if color.name == pair.role_a.name {
+                pair.role_b
+            } else {
+                pair.role_a
+            });
+        }
+        dependencies
+    }
+
+    /// Resolves `dynamic_color` to HCT using this scheme's spec version.
     #[must_use]
     pub fn get_hct(&self, dynamic_color: &DynamicColor) -> Hct {
+        if let Some(&argb) = self.role_overrides.get(&dynamic_color.name) {
+            return Hct::from_argb(argb);
+        }
         let pin = self.hct_cache.pin();
         if let Some(&hct) = pin.get(&dynamic_color.name) {
             return hct;
@@ -181,8 +501,13 @@ impl DynamicScheme {
         hct
     }
 
+    /// Resolves `dynamic_color` to ARGB using this scheme's spec version,
+    /// applying the color's opacity to the alpha channel if it has one.
     #[must_use]
     pub fn get_argb(&self, dynamic_color: &DynamicColor) -> Argb {
+        if let Some(&argb) = self.role_overrides.get(&dynamic_color.name) {
+            return argb;
+        }
         let pin = self.argb_cache.pin();
         if let Some(&argb) = pin.get(&dynamic_color.name) {
             return argb;
@@ -191,9 +516,7 @@ impl DynamicScheme {
         let hct = self.get_hct(dynamic_color);
         let mut argb = hct.to_argb();
 
-        if let Some(ref opacity_func) = dynamic_color.opacity
-            && let Some(opacity_percentage) = opacity_func(self)
-        {
+        if let Some(opacity_percentage) = dynamic_color.get_opacity(self) {
             let alpha = (opacity_percentage * 255.0).round() as u32;
             let alpha = alpha.clamp(0, 255);
             argb = Argb((argb.0 & 0x00ffffff) | (alpha << 24));
@@ -203,8 +526,26 @@ impl DynamicScheme {
         argb
     }
 
+    /// Resolves `dynamic_color` to an opaque ARGB color along with its opacity, for consumers
+    /// that track alpha separately. [`DynamicScheme::get_argb`] folds the opacity into the alpha
+    /// channel instead.
+    #[must_use]
+    pub fn get_argb_with_opacity(&self, dynamic_color: &DynamicColor) -> (Argb, Option<f64>) {
+        (
+            self.get_hct(dynamic_color).to_argb(),
+            dynamic_color.get_opacity(self),
+        )
+    }
+
+    /// Resolves the tone of `dynamic_color` using this scheme's spec version.
+    ///
+    /// This is the tone after contrast adjustments, so it can differ from the role's nominal
+    /// tone at non-standard contrast levels. A role override resolves to the override's L*.
     #[must_use]
     pub fn get_tone(&self, dynamic_color: &DynamicColor) -> f64 {
+        if let Some(&argb) = self.role_overrides.get(&dynamic_color.name) {
+            return argb.lstar();
+        }
         let pin = self.tone_cache.pin();
         if let Some(&tone) = pin.get(&dynamic_color.name) {
             return tone;
@@ -216,18 +557,35 @@ impl DynamicScheme {
         tone
     }
 
+    /// Picks the value for the hue segment the source color falls into.
+    ///
+    /// `hue_breakpoints` are ascending hues, usually starting at 0 and ending at 360. Segment `i`
+    /// covers `hue_breakpoints[i]..hue_breakpoints[i + 1]` (lower bound inclusive) and maps to
+    /// `values[i]`, so `values` needs at least `hue_breakpoints.len() - 1` entries. Extra trailing
+    /// values are ignored; the 2021 spec tables carry one. Returns the source hue if it falls
+    /// outside every segment.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `values` has fewer entries than there are segments.
     #[must_use]
     pub fn get_piecewise_value(
         source_color_hct: &Hct,
         hue_breakpoints: &[f64],
-        hues: &[f64],
+        values: &[f64],
     ) -> f64 {
-        let size = (hue_breakpoints.len().saturating_sub(1)).min(hues.len());
+        let size = hue_breakpoints.len().saturating_sub(1);
+        assert!(
+            values.len() >= size,
+            "expected at least {size} values for {} hue breakpoints, got {}",
+            hue_breakpoints.len(),
+            values.len()
+        );
         let source_hue = source_color_hct.hue();
 
         for i in 0..size {
             if source_hue >= hue_breakpoints[i] && source_hue < hue_breakpoints[i + 1] {
-                return MathUtils::sanitize_degrees_double(hues[i]);
+                return MathUtils::sanitize_degrees_double(values[i]);
             }
         }
 
@@ -236,17 +594,24 @@ impl DynamicScheme {
     }
 
     /// Given a hue and set of hue thresholds / rotations, returns the rotated hue.
+    ///
+    /// Follows the same breakpoint contract as [`DynamicScheme::get_piecewise_value`], with
+    /// `rotations[i]` being the rotation in degrees applied to hues in segment `i`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rotations` has fewer entries than there are segments.
     #[must_use]
     pub fn get_rotated_hue(
         source_color_hct: &Hct,
         hue_breakpoints: &[f64],
         rotations: &[f64],
     ) -> f64 {
-        let mut rotation = Self::get_piecewise_value(source_color_hct, hue_breakpoints, rotations);
-        let size = (hue_breakpoints.len().saturating_sub(1)).min(rotations.len());
-        if size == 0 {
-            rotation = 0.0;
-        }
+        let rotation = if hue_breakpoints.len() < 2 {
+            0.0
+        } else {
+            Self::get_piecewise_value(source_color_hct, hue_breakpoints, rotations)
+        };
         MathUtils::sanitize_degrees_double(source_color_hct.hue() + rotation)
     }
 
@@ -538,6 +903,68 @@ impl DynamicScheme {
     }
 }
 
+/// The serialized form of a [`DynamicScheme`], without the caches. Fields added to the scheme
+/// after it was first serializable get defaults here, so older JSON keeps deserializing.
+#[cfg(feature = "serde")]
+#[derive(Deserialize)]
+struct DynamicSchemeSurrogate {
+    source_color_hct_list: Vec<Hct>,
+    variant: Variant,
+    is_dark: bool,
+    contrast_level: f64,
+    #[serde(default)]
+    contrast_level_raw: Option<f64>,
+    platform: Platform,
+    spec_version: SpecVersion,
+    #[serde(default)]
+    requested_spec_version: Option<SpecVersion>,
+    primary_palette: TonalPalette,
+    secondary_palette: TonalPalette,
+    tertiary_palette: TonalPalette,
+    neutral_palette: TonalPalette,
+    neutral_variant_palette: TonalPalette,
+    error_palette: TonalPalette,
+    #[serde(default)]
+    role_overrides: HashMap<String, Argb>,
+    #[serde(default = "fix_disliked_colors_default")]
+    fix_disliked_colors: bool,
+}
+
+#[cfg(feature = "serde")]
+const fn fix_disliked_colors_default() -> bool {
+    true
+}
+
+#[cfg(feature = "serde")]
+impl From<DynamicSchemeSurrogate> for DynamicScheme {
+    fn from(surrogate: DynamicSchemeSurrogate) -> Self {
+        let contrast_level = surrogate.contrast_level.clamp(-1.0, 1.0);
+        Self {
+            source_color_hct_list: surrogate.source_color_hct_list,
+            variant: surrogate.variant,
+            is_dark: surrogate.is_dark,
+            contrast_level,
+            contrast_level_raw: surrogate.contrast_level_raw.unwrap_or(contrast_level),
+            platform: surrogate.platform,
+            spec_version: surrogate.spec_version,
+            requested_spec_version: surrogate
+                .requested_spec_version
+                .unwrap_or(surrogate.spec_version),
+            primary_palette: surrogate.primary_palette,
+            secondary_palette: surrogate.secondary_palette,
+            tertiary_palette: surrogate.tertiary_palette,
+            neutral_palette: surrogate.neutral_palette,
+            neutral_variant_palette: surrogate.neutral_variant_palette,
+            error_palette: surrogate.error_palette,
+            role_overrides: surrogate.role_overrides,
+            fix_disliked_colors: surrogate.fix_disliked_colors,
+            argb_cache: papaya::HashMap::new(),
+            tone_cache: papaya::HashMap::new(),
+            hct_cache: papaya::HashMap::new(),
+        }
+    }
+}
+
 fn dynamic_colors() -> &'static MaterialDynamicColors {
     static DYNAMIC_COLORS: OnceLock<MaterialDynamicColors> = OnceLock::new();
     DYNAMIC_COLORS.get_or_init(MaterialDynamicColors::new)
@@ -547,6 +974,7 @@ fn dynamic_colors() -> &'static MaterialDynamicColors {
 mod tests {
     #![allow(clippy::float_cmp)]
     use super::*;
+    use crate::contrast::contrast_utils::Contrast;
     use crate::utils::color_utils::Argb;
 
     #[test]
@@ -574,4 +1002,415 @@ mod tests {
         let rotated = DynamicScheme::get_rotated_hue(&hct, &hue_breakpoints, &rotations);
         assert!((rotated - expected_hue).abs() < 1e-4);
     }
+
+    #[test]
+    fn test_from_source_colors() {
+        let blue = Hct::from_argb(Argb(0xFF4285F4));
+        let red = Hct::from_argb(Argb(0xFFEA4335));
+        let from_colors = |colors: &[Hct]| {
+            DynamicScheme::from_source_colors(
+                colors,
+                Variant::Cmf,
+                false,
+                0.0,
+                Platform::Phone,
+                SpecVersion::Spec2026,
+            )
+        };
+
+        let single = from_colors(&[blue]);
+        let legacy = SchemeCmf::builder(blue, false, 0.0)
+            .spec_version(SpecVersion::Spec2026)
+            .build();
+        assert_eq!(single, legacy);
+        assert_eq!(single.tertiary(), legacy.tertiary());
+
+        let multi = from_colors(&[blue, red]);
+        assert_eq!(multi.source_color_hct_list, vec![blue, red]);
+        assert_eq!(multi.primary(), single.primary());
+        assert_ne!(multi.tertiary(), single.tertiary());
+    }
+
+    #[test]
+    fn test_get_argb_matches_reference() {
+        let scheme = SchemeTonalSpot::builder(Argb(0xFF0838D4), false, 0.0).build();
+        let primary = MaterialDynamicColors::new().primary();
+
+        assert_eq!(scheme.get_argb(&primary), Argb(0xFF515B92));
+        assert_eq!(scheme.get_hct(&primary).to_argb(), Argb(0xFF515B92));
+        assert_eq!(scheme.primary(), Argb(0xFF515B92));
+    }
+
+    #[test]
+    fn test_contrast_level_is_clamped() {
+        let source = Argb(0xFF4285F4);
+        let over = SchemeTonalSpot::builder(source, false, 2.0).build();
+        let max = SchemeTonalSpot::builder(source, false, 1.0).build();
+        assert_eq!(over.contrast_level, 1.0);
+        assert_eq!(over.contrast_level_raw, 2.0);
+        assert_eq!(over, max);
+        let over_dark = DynamicScheme::from_scheme(&over, true);
+        assert_eq!(over_dark.contrast_level, 1.0);
+        assert_eq!(over_dark.contrast_level_raw, 2.0);
+
+        let colors = MaterialDynamicColors::new();
+        for color in colors
+            .all_dynamic_colors()
+            .iter()
+            .filter_map(|getter| getter())
+        {
+            assert_eq!(over.get_argb(&color), max.get_argb(&color));
+        }
+
+        let standard = SchemeTonalSpot::builder(source, false, 0.0).build();
+        let reduced = SchemeTonalSpot::builder(source, false, -0.5).build();
+        let on_surface = colors.on_surface();
+        let surface = colors.surface();
+        let ratio = |scheme: &DynamicScheme| {
+            crate::contrast::contrast_utils::Contrast::ratio_of_tones(
+                scheme.get_tone(&on_surface),
+                scheme.get_tone(&surface),
+            )
+        };
+        assert!(ratio(&reduced) < ratio(&standard));
+    }
+
+    #[test]
+    fn test_get_piecewise_value_on_breakpoint() {
+        let hct = Hct::from_argb(Argb(0xff0000ff));
+        let hue_breakpoints = [0.0, hct.hue(), 360.0];
+        let values = [10.0, 20.0];
+
+        // Segments include their lower bound.
+        assert_eq!(
+            DynamicScheme::get_piecewise_value(&hct, &hue_breakpoints, &values),
+            20.0
+        );
+        let rotated = DynamicScheme::get_rotated_hue(&hct, &hue_breakpoints, &values);
+        assert!((rotated - MathUtils::sanitize_degrees_double(hct.hue() + 20.0)).abs() < 1e-9);
+    }
+
+    #[test]
+    #[should_panic(expected = "expected at least 3 values")]
+    fn test_get_piecewise_value_too_few_values() {
+        let hct = Hct::from_argb(Argb(0xff0000ff));
+        let _ = DynamicScheme::get_piecewise_value(&hct, &[0.0, 100.0, 200.0, 360.0], &[1.0, 2.0]);
+    }
+
+    #[test]
+    fn test_at_contrast() {
+        let source = Argb(0xFF4285F4);
+        let high = SchemeTonalSpot::builder(source, false, 1.0).build();
+        let standard = high.at_contrast(0.0);
+
+        assert_eq!(standard.contrast_level, 0.0);
+        assert_eq!(standard.primary_palette, high.primary_palette);
+        assert_eq!(
+            standard.neutral_variant_palette,
+            high.neutral_variant_palette
+        );
+        assert_eq!(
+            standard,
+            SchemeTonalSpot::builder(source, false, 0.0).build()
+        );
+        assert_ne!(standard.on_surface_variant(), high.on_surface_variant());
+    }
+
+    #[test]
+    fn test_role_override() {
+        let source = Argb(0xFF4285F4);
+        let standard = SchemeTonalSpot::builder(source, false, 0.0).build();
+        let mut overridden = standard.clone();
+        let _ = overridden.primary();
+        overridden.set_role_override("primary", Argb(0xFFB3E5FC));
+
+        assert_eq!(overridden.primary(), Argb(0xFFB3E5FC));
+        assert_eq!(
+            overridden.role_overrides().get("primary"),
+            Some(&Argb(0xFFB3E5FC))
+        );
+        assert_ne!(overridden.on_primary(), standard.on_primary());
+        assert_eq!(overridden.secondary(), standard.secondary());
+
+        let ratio = crate::contrast::contrast_utils::Contrast::ratio_of_tones(
+            overridden.on_primary().lstar(),
+            Argb(0xFFB3E5FC).lstar(),
+        );
+        assert!(ratio >= 4.5, "{ratio}");
+    }
+
+    #[test]
+    fn test_scheme_getters() {
+        let seed = Argb(0xFF0838D4);
+        let scheme = SchemeVibrant::builder(seed, true, 0.5).build();
+        assert_eq!(scheme.source_color_argb(), seed);
+        assert_eq!(scheme.variant(), Variant::Vibrant);
+        assert!(scheme.is_dark());
+        assert_eq!(scheme.platform(), Platform::Phone);
+        assert_eq!(scheme.contrast_level(), 0.5);
+
+        let seed_hct = Hct::from_argb(seed);
+        let source = scheme.source_color_hct();
+        assert!((source.hue() - seed_hct.hue()).abs() < 1e-6);
+        assert!((source.chroma() - seed_hct.chroma()).abs() < 1e-6);
+        assert!((source.tone() - seed_hct.tone()).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_with_variant() {
+        let neutral = SchemeNeutral::builder(Argb(0xFF4285F4), true, 0.5)
+            .spec_version(SpecVersion::Spec2026)
+            .platform(Platform::Watch)
+            .build();
+        let vibrant = neutral.with_variant(Variant::Vibrant);
+
+        assert_eq!(vibrant.variant, Variant::Vibrant);
+        assert_eq!(vibrant.source_color_hct_list, neutral.source_color_hct_list);
+        assert!(vibrant.is_dark);
+        assert_eq!(vibrant.contrast_level, 0.5);
+        assert_eq!(vibrant.platform, Platform::Watch);
+        assert_eq!(vibrant.spec_version, SpecVersion::Spec2025);
+        assert_eq!(vibrant.requested_spec_version, SpecVersion::Spec2026);
+        assert_eq!(
+            vibrant,
+            SchemeVibrant::builder(Argb(0xFF4285F4), true, 0.5)
+                .spec_version(SpecVersion::Spec2026)
+                .platform(Platform::Watch)
+                .build()
+        );
+
+        assert!(
+            Hct::from_argb(vibrant.primary()).chroma() > Hct::from_argb(neutral.primary()).chroma()
+        );
+    }
+
+    #[test]
+    fn test_with_variant_keeps_requested_spec_version() {
+        let source = [Hct::from_argb(Argb(0xFF4285F4))];
+        for &spec_version in SpecVersion::all() {
+            for &from in Variant::all() {
+                let scheme = DynamicScheme::from_source_colors(
+                    &source,
+                    from,
+                    false,
+                    0.0,
+                    Platform::Phone,
+                    spec_version,
+                );
+                for &to in Variant::all() {
+                    let expected = DynamicScheme::from_source_colors(
+                        &source,
+                        to,
+                        false,
+                        0.0,
+                        Platform::Phone,
+                        spec_version,
+                    );
+                    let switched = scheme.with_variant(to);
+                    assert_eq!(switched, expected, "{from:?} -> {to:?} at {spec_version:?}");
+                    assert_eq!(switched.requested_spec_version, spec_version);
+                    assert_eq!(switched.with_variant(from), scheme, "{to:?} -> {from:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        for spec_version in [
+            SpecVersion::Spec2021,
+            SpecVersion::Spec2025,
+            SpecVersion::Spec2026,
+        ] {
+            for is_dark in [false, true] {
+                let scheme = SchemeTonalSpot::builder(Argb(0xFF4285F4), is_dark, 0.0)
+                    .spec_version(spec_version)
+                    .build();
+                assert_eq!(scheme.validate(), Ok(()), "{spec_version:?}");
+            }
+        }
+
+        let scheme = SchemeTonalSpot::builder(Argb(0xFF4285F4), false, 0.0).build();
+        let looped = DynamicColor::builder("looped")
+            .palette(|s| s.primary_palette.clone())
+            .tone(|_| 50.0)
+            .background(|_| {
+                Some(Arc::new(
+                    DynamicColor::builder("looped")
+                        .palette(|s| s.primary_palette.clone())
+                        .tone(|_| 50.0)
+                        .build(),
+                ))
+            })
+            .build();
+        assert_eq!(
+            scheme.find_cycle(&looped, &mut Vec::new()),
+            Some(vec!["looped".to_string(), "looped".to_string()])
+        );
+        let primary = MaterialDynamicColors::new().primary();
+        assert_eq!(scheme.find_cycle(&primary, &mut Vec::new()), None);
+    }
+
+    #[test]
+    fn test_to_map() {
+        let mut scheme = SchemeTonalSpot::builder(Argb(0xFF4285F4), true, 0.0).build();
+        scheme.set_role_override("error", Argb(0xFFFF0000));
+        let map = scheme.to_map();
+
+        assert_eq!(map["primary"], scheme.primary());
+        assert_eq!(map["on_primary"], scheme.on_primary());
+        assert_eq!(map["surface"], scheme.surface());
+        assert_eq!(map["error"], Argb(0xFFFF0000));
+
+        let colors = MaterialDynamicColors::new_with_spec(scheme.spec_version);
+        for color in colors
+            .all_dynamic_colors()
+            .iter()
+            .filter_map(|getter| getter())
+        {
+            assert_eq!(map[&color.name], scheme.get_argb(&color), "{}", color.name);
+        }
+        assert!(map.keys().is_sorted());
+        // The 2021 spec has no dim roles; the 2025 spec does.
+        assert_eq!(scheme.spec_version, SpecVersion::Spec2021);
+        assert!(!map.contains_key("primary_dim"));
+        let scheme_2025 = SchemeTonalSpot::builder(Argb(0xFF4285F4), true, 0.0)
+            .spec_version(SpecVersion::Spec2025)
+            .build();
+        assert!(scheme_2025.to_map().contains_key("primary_dim"));
+    }
+
+    #[test]
+    fn test_from_preset() {
+        let source = Hct::from_argb(Argb(0xFF4285F4));
+        let scheme = |preset| {
+            DynamicScheme::from_preset(source, Variant::TonalSpot, false, preset, Platform::Phone)
+        };
+        let standard = scheme(ContrastPreset::Standard);
+        let high = scheme(ContrastPreset::High);
+        assert_eq!(high.contrast_level(), 1.0);
+        assert_eq!(
+            scheme(ContrastPreset::Medium),
+            DynamicScheme::from_source_colors(
+                &[source],
+                Variant::TonalSpot,
+                false,
+                0.5,
+                Platform::Phone,
+                SpecVersion::latest(),
+            )
+        );
+
+        let on_surface_contrast = |s: &DynamicScheme| {
+            Contrast::ratio_of_tones(s.on_surface().lstar(), s.surface().lstar())
+        };
+        assert!(on_surface_contrast(&high) > on_surface_contrast(&standard));
+    }
+
+    #[test]
+    fn test_get_tone() {
+        let primary = MaterialDynamicColors::new().primary();
+        let light = SchemeTonalSpot::builder(Argb(0xFF4285F4), false, 0.0).build();
+        let dark = SchemeTonalSpot::builder(Argb(0xFF4285F4), true, 0.0).build();
+
+        assert_eq!(light.get_tone(&primary), 40.0);
+        assert_eq!(dark.get_tone(&primary), 80.0);
+        assert_eq!(light.get_tone(&primary), primary.get_tone(&light));
+    }
+
+    #[test]
+    fn test_resolve_all() {
+        let scheme = SchemeTonalSpot::builder(Argb(0xFF4285F4), false, 1.0).build();
+        let resolved = scheme.resolve_all();
+        // Every role was solved to HCT exactly once, even though roles reference each other.
+        assert_eq!(scheme.hct_cache.pin().len(), resolved.len());
+
+        let fresh = SchemeTonalSpot::builder(Argb(0xFF4285F4), false, 1.0).build();
+        let colors = MaterialDynamicColors::new_with_spec(scheme.spec_version);
+        for color in colors
+            .all_dynamic_colors()
+            .iter()
+            .filter_map(|getter| getter())
+        {
+            assert_eq!(resolved.get(&color.name), Some(fresh.get_argb(&color)));
+            assert_eq!(resolved.tone(&color.name), Some(fresh.get_tone(&color)));
+        }
+        assert_eq!(scheme.hct_cache.pin().len(), resolved.len());
+        assert_eq!(resolved.get("on_surface"), Some(scheme.on_surface()));
+        assert_eq!(resolved.get("not_a_role"), None);
+        assert_eq!(resolved.get("primary_dim"), None);
+    }
+
+    #[test]
+    fn test_clone_resolves_identically() {
+        let scheme = SchemeTonalSpot::builder(Argb(0xFF4285F4), true, 0.5).build();
+        let unresolved = scheme.clone();
+        let resolved = scheme.resolve_all();
+        let warm = scheme.clone();
+
+        assert_eq!(unresolved, scheme);
+        assert_eq!(unresolved.resolve_all(), resolved);
+        assert_eq!(warm.hct_cache.pin().len(), resolved.len());
+        assert_eq!(warm.resolve_all(), resolved);
+
+        let mut fork = scheme.clone();
+        fork.set_role_override("primary", Argb(0xFFB3E5FC));
+        assert_eq!(fork.primary(), Argb(0xFFB3E5FC));
+        assert_eq!(scheme.resolve_all(), resolved);
+    }
+
+    #[test]
+    fn test_fix_disliked_colors() {
+        use crate::dynamic::color_specs::ColorSpecs;
+
+        // A dark yellow-green seed, used as its own tertiary palette.
+        let seed = Hct::new(100.0, 30.0, 40.0);
+        let palette = TonalPalette::from_hct(seed);
+        let mut scheme = DynamicScheme::new(
+            seed,
+            Variant::Fidelity,
+            false,
+            0.0,
+            palette.clone(),
+            palette.clone(),
+            palette.clone(),
+            palette.clone(),
+            palette.clone(),
+            palette,
+        );
+        let tertiary_container = ColorSpecs::get(SpecVersion::Spec2021)
+            .call()
+            .tertiary_container();
+        assert!(scheme.fix_disliked_colors);
+        assert!(((tertiary_container.tone)(&scheme) - 70.0).abs() < 1.0);
+
+        scheme.set_fix_disliked_colors(false);
+        assert!(((tertiary_container.tone)(&scheme) - seed.tone()).abs() < 1.0);
+        assert!(!scheme.with_variant(Variant::Content).fix_disliked_colors);
+        assert!(!scheme.at_contrast(0.5).fix_disliked_colors);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn test_serde_round_trip() {
+        let scheme = SchemeTonalSpot::builder(Argb(0xFF4285F4), false, 2.0).build();
+        let json = serde_json::to_string(&scheme).expect("serializable");
+        let back: DynamicScheme = serde_json::from_str(&json).expect("deserializable");
+        assert_eq!(back, scheme);
+        assert_eq!(back.contrast_level_raw, 2.0);
+        assert_eq!(back.primary(), scheme.primary());
+
+        // Schemes serialized before `contrast_level_raw` existed.
+        let mut value: serde_json::Value = serde_json::from_str(&json).expect("valid JSON");
+        let object = value.as_object_mut().expect("object");
+        object.remove("contrast_level_raw");
+        object.remove("role_overrides");
+        object.remove("fix_disliked_colors");
+        let old: DynamicScheme = serde_json::from_value(value).expect("deserializable");
+        assert_eq!(old.contrast_level, 1.0);
+        assert_eq!(old.contrast_level_raw, 1.0);
+        assert!(old.role_overrides().is_empty());
+        assert!(old.fix_disliked_colors);
+        assert_eq!(old, scheme);
+    }
 }
@@ -461,4 +461,111 @@ mod tests {
         assert!(colors[0]().is_some());
         assert!(colors[10]().is_some());
     }
+
+    #[test]
+    fn test_spec_2026_resolves_all_roles() {
+        use crate::dynamic::color_spec::{Platform, SpecVersion};
+        use crate::dynamic::dynamic_scheme::DynamicScheme;
+        use crate::scheme::{
+            SchemeCmf, SchemeExpressive, SchemeNeutral, SchemeTonalSpot, SchemeVibrant,
+        };
+        use crate::utils::color_utils::Argb;
+
+        type SchemeFn = fn(Argb, bool, f64, Platform) -> DynamicScheme;
+        let schemes: [SchemeFn; 5] = [
+            |c, d, l, p| {
+                SchemeCmf::builder(c, d, l)
+                    .spec_version(SpecVersion::Spec2026)
+                    .platform(p)
+                    .build()
+            },
+            |c, d, l, p| {
+                SchemeTonalSpot::builder(c, d, l)
+                    .spec_version(SpecVersion::Spec2026)
+                    .platform(p)
+                    .build()
+            },
+            |c, d, l, p| {
+                SchemeVibrant::builder(c, d, l)
+                    .spec_version(SpecVersion::Spec2026)
+                    .platform(p)
+                    .build()
+            },
+            |c, d, l, p| {
+                SchemeExpressive::builder(c, d, l)
+                    .spec_version(SpecVersion::Spec2026)
+                    .platform(p)
+                    .build()
+            },
+            |c, d, l, p| {
+                SchemeNeutral::builder(c, d, l)
+                    .spec_version(SpecVersion::Spec2026)
+                    .platform(p)
+                    .build()
+            },
+        ];
+
+        let mdc = MaterialDynamicColors::new_with_spec(SpecVersion::Spec2026);
+        for make_scheme in schemes {
+            for is_dark in [false, true] {
+                for contrast_level in [-1.0, 0.0, 1.0] {
+                    for &platform in Platform::all() {
+                        let scheme =
+                            make_scheme(Argb(0xFF4285F4), is_dark, contrast_level, platform);
+                        for getter in mdc.all_dynamic_colors() {
+                            if let Some(color) = getter() {
+                                let tone = scheme.get_tone(&color);
+                                assert!((0.0..=100.0).contains(&tone), "{}", color.name);
+                                let _ = scheme.get_argb(&color);
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_desktop_resolves_like_phone() {
+        use crate::dynamic::color_spec::{Platform, SpecVersion};
+        use crate::dynamic::dynamic_scheme::DynamicScheme;
+        use crate::dynamic::variant::Variant;
+        use crate::hct::hct_color::Hct;
+        use crate::utils::color_utils::Argb;
+
+        assert_ne!(Platform::Desktop, Platform::Phone);
+        let seed = [Hct::from_argb(Argb(0xFF4285F4))];
+        for spec_version in [SpecVersion::Spec2025, SpecVersion::Spec2026] {
+            let mdc = MaterialDynamicColors::new_with_spec(spec_version);
+            for variant in [Variant::TonalSpot, Variant::Neutral, Variant::Expressive] {
+                for is_dark in [false, true] {
+                    let scheme = |platform| {
+                        DynamicScheme::from_source_colors(
+                            &seed,
+                            variant,
+                            is_dark,
+                            0.0,
+                            platform,
+                            spec_version,
+                        )
+                    };
+                    let phone = scheme(Platform::Phone);
+                    let desktop = scheme(Platform::Desktop);
+                    assert_eq!(desktop.platform, Platform::Desktop);
+                    for color in mdc
+                        .all_dynamic_colors()
+                        .iter()
+                        .filter_map(|getter| getter())
+                    {
+                        assert_eq!(
+                            phone.get_argb(&color),
+                            desktop.get_argb(&color),
+                            "{} in {spec_version:?} {variant:?}",
+                            color.name
+                        );
+                    }
+                }
+            }
+        }
+    }
 }
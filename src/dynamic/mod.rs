@@ -8,5 +8,11 @@ pub mod contrast_curve;
 pub mod dynamic_color;
 pub mod dynamic_scheme;
 pub mod material_dynamic_colors;
+pub mod resolved_scheme;
+pub mod scheme_diff;
 pub mod tone_delta_pair;
+pub mod tone_utils;
 pub mod variant;
+
+pub use resolved_scheme::{ResolvedRole, ResolvedScheme};
+pub use scheme_diff::scheme_diff;
@@ -0,0 +1,55 @@
+use crate::utils::color_utils::Argb;
+use indexmap::IndexMap;
+
+/// A role resolved against a scheme: its final color and the tone it landed on.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ResolvedRole {
+    pub argb: Argb,
+    pub tone: f64,
+}
+
+/// Every role of a [`DynamicScheme`], resolved once. Built by [`DynamicScheme::resolve_all`].
+///
+/// Roles are keyed by name (e.g. `"on_primary"`), in the order of
+/// [`MaterialDynamicColors::all_dynamic_colors`] for the scheme's spec version. Roles that spec
+/// doesn't define are absent, e.g. `primary_dim` in a 2021 scheme.
+///
+/// [`DynamicScheme`]: crate::dynamic::dynamic_scheme::DynamicScheme
+/// [`DynamicScheme::resolve_all`]: crate::dynamic::dynamic_scheme::DynamicScheme::resolve_all
+/// [`MaterialDynamicColors::all_dynamic_colors`]:
+///     crate::dynamic::material_dynamic_colors::MaterialDynamicColors::all_dynamic_colors
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct ResolvedScheme {
+    pub roles: IndexMap<String, ResolvedRole>,
+}
+
+impl ResolvedScheme {
+    /// The resolved color of the role called `name`, with opacity folded into the alpha channel
+    /// as in [`DynamicScheme::get_argb`].
+    ///
+    /// [`DynamicScheme::get_argb`]: crate::dynamic::dynamic_scheme::DynamicScheme::get_argb
+    #[must_use]
+    pub fn get(&self, name: &str) -> Option<Argb> {
+        self.roles.get(name).map(|role| role.argb)
+    }
+
+    /// The resolved tone of the role called `name`, if the scheme has it.
+    #[must_use]
+    pub fn tone(&self, name: &str) -> Option<f64> {
+        self.roles.get(name).map(|role| role.tone)
+    }
+
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.roles.len()
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.roles.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &ResolvedRole)> {
+        self.roles.iter().map(|(name, role)| (name.as_str(), role))
+    }
+}
@@ -0,0 +1,61 @@
+use crate::dynamic::dynamic_scheme::DynamicScheme;
+use crate::dynamic::material_dynamic_colors::MaterialDynamicColors;
+use crate::utils::color_utils::Argb;
+use std::collections::HashSet;
+
+/// Lists the roles whose resolved ARGB differs between two schemes, as `(name, a, b)`.
+///
+/// Roles are taken from [`MaterialDynamicColors::all_dynamic_colors`] for `a`'s spec version, in
+/// that order, skipping those `b`'s spec version doesn't define. Useful for checking what a spec
+/// upgrade or a change in scheme parameters actually changes.
+#[must_use]
+pub fn scheme_diff(a: &DynamicScheme, b: &DynamicScheme) -> Vec<(String, Argb, Argb)> {
+    let roles_b: HashSet<String> = MaterialDynamicColors::new_with_spec(b.spec_version)
+        .all_dynamic_colors()
+        .iter()
+        .filter_map(|getter| getter())
+        .map(|color| color.name.clone())
+        .collect();
+    MaterialDynamicColors::new_with_spec(a.spec_version)
+        .all_dynamic_colors()
+        .iter()
+        .filter_map(|getter| getter())
+        .filter(|color| roles_b.contains(&color.name))
+        .filter_map(|color| {
+            let (argb_a, argb_b) = (a.get_argb(&color), b.get_argb(&color));
+            (argb_a != argb_b).then(|| (color.name.clone(), argb_a, argb_b))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::color_spec::SpecVersion;
+    use crate::scheme::SchemeTonalSpot;
+
+    #[test]
+    fn test_scheme_diff() {
+        let standard = SchemeTonalSpot::builder(Argb(0xff4285f4), false, 0.0).build();
+        assert!(scheme_diff(&standard, &standard).is_empty());
+
+        let high = SchemeTonalSpot::builder(Argb(0xff4285f4), false, 1.0).build();
+        let diff = scheme_diff(&standard, &high);
+        let names: Vec<&str> = diff.iter().map(|(name, _, _)| name.as_str()).collect();
+        assert!(names.contains(&"primary"), "{names:?}");
+        assert!(!names.contains(&"primary_palette_key_color"));
+        assert!(diff.iter().all(|(_, a, b)| a != b));
+    }
+
+    #[test]
+    fn test_scheme_diff_across_spec_versions() {
+        let old = SchemeTonalSpot::builder(Argb(0xff4285f4), false, 0.0).build();
+        let new = SchemeTonalSpot::builder(Argb(0xff4285f4), false, 0.0)
+            .spec_version(SpecVersion::Spec2025)
+            .build();
+        for diff in [scheme_diff(&old, &new), scheme_diff(&new, &old)] {
+            assert!(!diff.is_empty());
+            assert!(diff.iter().all(|(name, _, _)| name != "primary_dim"));
+        }
+    }
+}
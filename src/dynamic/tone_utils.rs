@@ -0,0 +1,75 @@
+//! Tone search helpers shared by the 2025 and 2026 color specs.
+
+use crate::hct::hct_color::Hct;
+use crate::palettes::tonal_palette::TonalPalette;
+
+/// Walks from `tone` in steps of 1 until a color with at least `chroma` is found, returning the
+/// tone of the most chromatic candidate seen. Stops once the walk leaves 0..=100.
+#[must_use]
+pub fn find_best_tone_for_chroma(
+    hue: f64,
+    chroma: f64,
+    mut tone: f64,
+    by_decreasing_tone: bool,
+) -> f64 {
+    let mut answer = tone;
+    let mut best_candidate = Hct::new(hue, chroma, answer);
+    while best_candidate.chroma() < chroma {
+        if !(0.0..=100.0).contains(&tone) {
+            break;
+        }
+        tone += if by_decreasing_tone { -1.0 } else { 1.0 };
+        let new_candidate = Hct::new(hue, chroma, tone);
+        if best_candidate.chroma() < new_candidate.chroma() {
+            best_candidate = new_candidate;
+            answer = tone;
+        }
+    }
+    answer
+}
+
+/// The highest tone at which `palette` reaches its chroma (scaled by `chroma_multiplier`),
+/// clamped to `lower_bound..=upper_bound`.
+#[must_use]
+pub fn t_max_c(
+    palette: &TonalPalette,
+    lower_bound: f64,
+    upper_bound: f64,
+    chroma_multiplier: f64,
+) -> f64 {
+    let answer =
+        find_best_tone_for_chroma(palette.hue, palette.chroma * chroma_multiplier, 100.0, true);
+    answer.clamp(lower_bound, upper_bound)
+}
+
+/// The lowest tone at which `palette` reaches its chroma, clamped to `lower_bound..=upper_bound`.
+#[must_use]
+pub fn t_min_c(palette: &TonalPalette, lower_bound: f64, upper_bound: f64) -> f64 {
+    let answer = find_best_tone_for_chroma(palette.hue, palette.chroma, 0.0, false);
+    answer.clamp(lower_bound, upper_bound)
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+    use super::*;
+    use crate::utils::color_utils::Argb;
+
+    #[test]
+    fn test_t_max_c_saturated_red() {
+        // Pure red is the most chromatic color at its hue, at tone ~53.2.
+        let red = TonalPalette::from_argb(Argb(0xffff0000));
+        let tone = t_max_c(&red, 0.0, 100.0, 1.0);
+        assert!((50.0..=56.0).contains(&tone), "{tone}");
+        assert_eq!(t_max_c(&red, 0.0, 40.0, 1.0), 40.0);
+        assert_eq!(t_max_c(&red, 70.0, 100.0, 1.0), 70.0);
+    }
+
+    #[test]
+    fn test_grey_palette_bounds() {
+        let grey = TonalPalette::from_hue_and_chroma(0.0, 0.0);
+        assert_eq!(t_max_c(&grey, 0.0, 100.0, 1.0), 100.0);
+        assert_eq!(t_min_c(&grey, 0.0, 100.0), 0.0);
+        assert_eq!(t_min_c(&grey, 10.0, 90.0), 10.0);
+    }
+}
@@ -1,3 +1,7 @@
+use crate::utils::error::VariantParseError;
+use std::fmt;
+use std::str::FromStr;
+
 /// Themes for Dynamic Color.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -23,3 +27,76 @@ pub enum Variant {
     /// not sure what this one does
     Cmf,
 }
+
+impl Variant {
+    /// Every variant, in declaration order.
+    #[must_use]
+    pub const fn all() -> &'static [Self] {
+        &[
+            Self::Monochrome,
+            Self::Neutral,
+            Self::TonalSpot,
+            Self::Vibrant,
+            Self::Expressive,
+            Self::Fidelity,
+            Self::Content,
+            Self::Rainbow,
+            Self::FruitSalad,
+            Self::Cmf,
+        ]
+    }
+
+    /// Canonical `snake_case` name, as used by [`Display`](fmt::Display) and [`FromStr`].
+    #[must_use]
+    pub const fn as_str(&self) -> &'static str {
+        match self {
+            Self::Monochrome => "monochrome",
+            Self::Neutral => "neutral",
+            Self::TonalSpot => "tonal_spot",
+            Self::Vibrant => "vibrant",
+            Self::Expressive => "expressive",
+            Self::Fidelity => "fidelity",
+            Self::Content => "content",
+            Self::Rainbow => "rainbow",
+            Self::FruitSalad => "fruit_salad",
+            Self::Cmf => "cmf",
+        }
+    }
+}
+
+impl fmt::Display for Variant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for Variant {
+    type Err = VariantParseError;
+
+    /// Parses a variant name case-insensitively, e.g. `"tonal_spot"` or `"TONAL_SPOT"`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim();
+        Self::all()
+            .iter()
+            .find(|variant| variant.as_str().eq_ignore_ascii_case(name))
+            .copied()
+            .ok_or_else(|| VariantParseError(s.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_variant_round_trip() {
+        for &variant in Variant::all() {
+            assert_eq!(variant.to_string().parse::<Variant>(), Ok(variant));
+        }
+        assert_eq!("Tonal_Spot".parse::<Variant>(), Ok(Variant::TonalSpot));
+        assert_eq!(
+            "pastel".parse::<Variant>(),
+            Err(VariantParseError("pastel".to_string()))
+        );
+    }
+}
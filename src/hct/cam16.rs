@@ -1,3 +1,4 @@
+use crate::hct::hct_color::Hct;
 use crate::hct::viewing_conditions::ViewingConditions;
 use crate::utils::color_utils::{Argb, ColorUtils, Xyz};
 use crate::utils::math_utils::MathUtils;
@@ -106,6 +107,16 @@ impl Cam16 {
         self.viewed(&ViewingConditions::default())
     }
 
+    /// HCT representation of the color, converted through [`Cam16::to_argb`].
+    ///
+    /// This is lossy: the color is rounded to 8-bit sRGB, so hue and chroma only carry over to
+    /// within that precision, and a color outside the sRGB gamut is clipped per channel, which
+    /// can move its hue as well as its chroma. Use [`Hct::new`] to gamut map while keeping the hue.
+    #[must_use]
+    pub fn to_hct(&self) -> Hct {
+        Hct::from_argb(self.to_argb())
+    }
+
     /// ARGB representation of the color, in defined viewing conditions.
     #[must_use]
     pub fn viewed(&self, viewing_conditions: &ViewingConditions) -> Argb {
@@ -159,6 +170,37 @@ impl Cam16 {
         Self::from_argb_in_viewing_conditions(argb, &ViewingConditions::default())
     }
 
+    /// Converts a buffer of colors to CAM16, assuming default viewing conditions.
+    ///
+    /// Equivalent to calling [`Cam16::from_argb`] on every pixel, but the viewing conditions are
+    /// only computed once for the whole buffer.
+    #[must_use]
+    pub fn from_argb_slice(pixels: &[Argb]) -> Vec<Self> {
+        let viewing_conditions = ViewingConditions::default();
+        pixels
+            .iter()
+            .map(|&argb| Self::from_argb_in_viewing_conditions(argb, &viewing_conditions))
+            .collect()
+    }
+
+    /// Converts a buffer of colors to CAM16 into a preallocated output buffer, assuming default
+    /// viewing conditions.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `pixels` and `out` have different lengths.
+    pub fn from_argb_into(pixels: &[Argb], out: &mut [Self]) {
+        assert_eq!(
+            pixels.len(),
+            out.len(),
+            "input and output buffers must have the same length"
+        );
+        let viewing_conditions = ViewingConditions::default();
+        for (&argb, cam) in pixels.iter().zip(out.iter_mut()) {
+            *cam = Self::from_argb_in_viewing_conditions(argb, &viewing_conditions);
+        }
+    }
+
     /// Create a CAM16 color from a color in defined viewing conditions.
     #[must_use]
     pub fn from_argb_in_viewing_conditions(
@@ -237,11 +279,16 @@ impl Cam16 {
         Self::new(hue, c, j, q, m, s, jstar, astar, bstar)
     }
 
+    /// Create a CAM16 color from lightness `j`, chroma `c` and hue `h` in degrees, assuming the
+    /// color was viewed in default viewing conditions. Use [`Cam16::viewed`] or
+    /// [`Cam16::to_argb`] to render it.
     #[must_use]
     pub fn from_jch(j: f64, c: f64, h: f64) -> Self {
         Self::from_jch_in_viewing_conditions(j, c, h, &ViewingConditions::default())
     }
 
+    /// Create a CAM16 color from lightness `j`, chroma `c` and hue `h` in degrees, in defined
+    /// viewing conditions.
     #[must_use]
     pub fn from_jch_in_viewing_conditions(
         j: f64,
@@ -264,11 +311,15 @@ impl Cam16 {
         Self::new(h, c, j, q, m, s, jstar, astar, bstar)
     }
 
+    /// Create a CAM16 color from CAM16-UCS coordinates `jstar`, `astar` and `bstar`, assuming the
+    /// color was viewed in default viewing conditions.
     #[must_use]
     pub fn from_ucs(jstar: f64, astar: f64, bstar: f64) -> Self {
         Self::from_ucs_in_viewing_conditions(jstar, astar, bstar, &ViewingConditions::default())
     }
 
+    /// Create a CAM16 color from CAM16-UCS coordinates `jstar`, `astar` and `bstar`, in defined
+    /// viewing conditions.
     #[must_use]
     pub fn from_ucs_in_viewing_conditions(
         jstar: f64,
@@ -321,4 +372,105 @@ mod tests {
         // Distance between Red and Blue in CAM16-UCS is around 21.42
         assert!((dist - 21.42).abs() < 0.1);
     }
+
+    #[test]
+    fn test_cam16_batch_matches_scalar() {
+        let pixels: Vec<Argb> = (0..4096u32)
+            .map(|i| Argb(0xFF00_0000 | (i.wrapping_mul(2_654_435_761) & 0x00FF_FFFF)))
+            .collect();
+        let scalar: Vec<Cam16> = pixels.iter().map(|&p| Cam16::from_argb(p)).collect();
+
+        assert_eq!(Cam16::from_argb_slice(&pixels), scalar);
+
+        let mut out = vec![Cam16::from_argb(Argb(0xFF000000)); pixels.len()];
+        Cam16::from_argb_into(&pixels, &mut out);
+        assert_eq!(out, scalar);
+    }
+
+    #[test]
+    fn test_hct_round_trip() {
+        for argb in [
+            Argb(0xff4285f4),
+            Argb(0xffea4335),
+            Argb(0xff34a853),
+            Argb(0xff808080),
+        ] {
+            let hct = Hct::from_argb(argb);
+            let cam = hct.to_cam16();
+            assert!((cam.hue - hct.hue()).abs() < 1e-9);
+            assert!((cam.chroma - hct.chroma()).abs() < 1e-9);
+
+            let back = cam.to_hct();
+            assert_eq!(back.to_argb(), argb);
+            assert!((back.tone() - hct.tone()).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_to_hct_goes_through_argb() {
+        // In gamut, fractional values come back to within 8-bit precision.
+        let cam = Cam16::from_jch(60.25, 30.5, 200.75);
+        let hct = cam.to_hct();
+        assert!(MathUtils::difference_degrees(hct.hue(), cam.hue) < 1.0);
+        assert!((hct.chroma() - cam.chroma).abs() < 1.0);
+        assert_eq!(hct, Hct::from_argb(cam.to_argb()));
+
+        // Out of gamut, the result is the clipped ARGB color, not a gamut-mapped one.
+        let cam = Cam16::from_jch(50.0, 200.0, 140.0);
+        let hct = cam.to_hct();
+        assert_eq!(hct, Hct::from_argb(cam.to_argb()));
+        assert!(hct.chroma() < 200.0);
+    }
+
+    #[test]
+    fn test_cam16_reference_values() {
+        // Reference values from the upstream material-color-utilities CAM16 tests.
+        let red = Cam16::from_argb(Argb(0xffff0000));
+        assert!((red.hue - 27.408).abs() < 0.01);
+        assert!((red.chroma - 113.357).abs() < 0.01);
+        assert!((red.j - 46.445).abs() < 0.01);
+        assert!((red.m - 89.494).abs() < 0.01);
+        assert!((red.s - 91.889).abs() < 0.01);
+        assert!((red.q - 105.988).abs() < 0.01);
+
+        let blue = Cam16::from_argb(Argb(0xff0000ff));
+        assert!((blue.hue - 282.788).abs() < 0.01);
+        assert!((blue.chroma - 87.230).abs() < 0.01);
+        assert!((blue.j - 25.465).abs() < 0.01);
+        assert!((blue.m - 68.867).abs() < 0.01);
+        assert!((blue.s - 93.674).abs() < 0.01);
+        assert!((blue.q - 78.481).abs() < 0.01);
+
+        // CAM16-UCS coordinates follow from J, M and h.
+        for cam in [red, blue] {
+            let jstar = 1.7 * cam.j / 0.007f64.mul_add(cam.j, 1.0);
+            let mstar = (0.0228 * cam.m).ln_1p() / 0.0228;
+            assert!((cam.jstar - jstar).abs() < 1e-9);
+            assert!((cam.astar - mstar * cam.hue.to_radians().cos()).abs() < 1e-9);
+            assert!((cam.bstar - mstar * cam.hue.to_radians().sin()).abs() < 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_from_jch_and_ucs_reproduce_argb() {
+        for argb in [
+            Argb(0xff4285f4),
+            Argb(0xffea4335),
+            Argb(0xff34a853),
+            Argb(0xfffbbc05),
+            Argb(0xff202124),
+        ] {
+            let cam = Cam16::from_argb(argb);
+            assert_eq!(Cam16::from_jch(cam.j, cam.chroma, cam.hue).to_argb(), argb);
+            assert_eq!(
+                Cam16::from_ucs(cam.jstar, cam.astar, cam.bstar).to_argb(),
+                argb
+            );
+
+            let vc = ViewingConditions::builder().background_lstar(20.0).build();
+            let cam = Cam16::from_argb_in_viewing_conditions(argb, &vc);
+            let from_jch = Cam16::from_jch_in_viewing_conditions(cam.j, cam.chroma, cam.hue, &vc);
+            assert_eq!(from_jch.viewed(&vc), argb);
+        }
+    }
 }
@@ -1,9 +1,24 @@
 use crate::hct::cam16::Cam16;
 use crate::hct::hct_solver::HctSolver;
-use crate::hct::viewing_conditions::ViewingConditions;
+use crate::hct::viewing_conditions::{ViewingConditions, WhitePoint};
 use crate::utils::color_utils::{Argb, ColorUtils};
+use crate::utils::math_utils::MathUtils;
 use std::fmt;
 
+/// Broad hue family a color belongs to, as returned by [`Hct::hue_family`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HueFamily {
+    Red,
+    Orange,
+    Yellow,
+    Green,
+    Cyan,
+    Blue,
+    Purple,
+    Magenta,
+}
+
 /// HCT, hue, chroma, and tone. A color system that provides a perceptually accurate color
 /// measurement system that can also accurately render what colors will appear as in different
 /// lighting environments.
@@ -19,12 +34,29 @@ use std::fmt;
 /// Unlike contrast ratio, measuring contrast in L* is linear, and simple to calculate. A difference
 /// of 40 in HCT tone guarantees a contrast ratio >= 3.0, and a difference of 50 guarantees a
 /// contrast ratio >= 4.5.
-#[derive(Debug, Clone, Copy, PartialEq)]
+///
+/// `Hct` implements `Eq` and `Hash` so it can be used as a map key. Equality compares hue, chroma,
+/// tone and ARGB exactly, without any rounding, and ignores the chroma originally requested.
+/// Hashing uses only the ARGB value, which equal colors always share. Colors built the same way,
+/// e.g. two `Hct::new` calls with identical arguments, are therefore equal and hash the same.
+#[derive(Debug, Clone, Copy)]
 pub struct Hct {
     hue: f64,
     chroma: f64,
     tone: f64,
     argb: Argb,
+    /// The chroma asked for when this color was solved, before gamut mapping.
+    requested_chroma: f64,
+}
+
+impl PartialEq for Hct {
+    #[allow(clippy::float_cmp)]
+    fn eq(&self, other: &Self) -> bool {
+        self.hue == other.hue
+            && self.chroma == other.chroma
+            && self.tone == other.tone
+            && self.argb == other.argb
+    }
 }
 
 impl Eq for Hct {}
@@ -36,13 +68,17 @@ impl std::hash::Hash for Hct {
 }
 
 impl Hct {
-    fn new_internal(argb: Argb) -> Self {
+    /// How far below the requested chroma a solved color may fall and still count as in gamut.
+    const GAMUT_CHROMA_TOLERANCE: f64 = 0.5;
+
+    fn new_internal(argb: Argb, requested_chroma: Option<f64>) -> Self {
         let cam = Cam16::from_argb(argb);
         Self {
             hue: cam.hue,
             chroma: cam.chroma,
             tone: argb.lstar(),
             argb,
+            requested_chroma: requested_chroma.unwrap_or(cam.chroma),
         }
     }
 
@@ -61,7 +97,7 @@ impl Hct {
     #[must_use]
     pub fn new(hue: f64, chroma: f64, tone: f64) -> Self {
         let argb = HctSolver::solve_to_argb(hue, chroma, tone);
-        Self::new_internal(argb)
+        Self::new_internal(argb, Some(chroma))
     }
 
     /// Create an HCT color from a color.
@@ -75,7 +111,95 @@ impl Hct {
     /// HCT representation of a color in default viewing conditions.
     #[must_use]
     pub fn from_argb(argb: Argb) -> Self {
-        Self::new_internal(argb)
+        Self::new_internal(argb, None)
+    }
+
+    /// Converts a buffer of colors to HCT, e.g. to preprocess image pixels.
+    ///
+    /// Equivalent to calling [`Hct::from_argb`] on every pixel, but the viewing conditions are only
+    /// computed once, and runs of identical pixels are only converted once.
+    #[must_use]
+    pub fn from_argb_slice(pixels: &[Argb]) -> Vec<Self> {
+        let viewing_conditions = ViewingConditions::default();
+        let mut previous: Option<Self> = None;
+        pixels
+            .iter()
+            .map(|&argb| {
+                let hct = match previous {
+                    Some(hct) if hct.argb == argb => hct,
+                    _ => {
+                        let cam = Cam16::from_argb_in_viewing_conditions(argb, &viewing_conditions);
+                        Self {
+                            hue: cam.hue,
+                            chroma: cam.chroma,
+                            tone: argb.lstar(),
+                            argb,
+                            requested_chroma: cam.chroma,
+                        }
+                    }
+                };
+                previous = Some(hct);
+                hct
+            })
+            .collect()
+    }
+
+    /// Create an HCT color from a color, as seen under `white_point` in otherwise default viewing
+    /// conditions. See [`Hct::from_argb_in`].
+    ///
+    /// With [`WhitePoint::D65`] this is the same as [`Hct::from_argb`].
+    #[must_use]
+    pub fn from_argb_with_white_point(argb: Argb, white_point: WhitePoint) -> Self {
+        let vc = ViewingConditions::builder()
+            .white_point(white_point.xyz())
+            .build();
+        Self::from_argb_in(argb, &vc)
+    }
+
+    /// Create an HCT color from a color, as seen in the given viewing conditions.
+    ///
+    /// Hue and chroma are measured with CAM16 in `vc` instead of the default viewing conditions,
+    /// tone is unaffected. `to_argb` returns `argb` unchanged. Note that the setters solve in
+    /// default viewing conditions.
+    #[must_use]
+    pub fn from_argb_in(argb: Argb, vc: &ViewingConditions) -> Self {
+        let cam = Cam16::from_argb_in_viewing_conditions(argb, vc);
+        Self {
+            hue: cam.hue,
+            chroma: cam.chroma,
+            tone: argb.lstar(),
+            argb,
+            requested_chroma: cam.chroma,
+        }
+    }
+
+    /// Whether the requested hue, chroma and tone can be shown in sRGB without losing chroma.
+    ///
+    /// `Hct::new` silently lowers chroma for colors outside of the sRGB gamut, this returns false
+    /// when that would happen.
+    #[must_use]
+    pub fn is_in_gamut(hue: f64, chroma: f64, tone: f64) -> bool {
+        Self::new(hue, chroma, tone).chroma() >= chroma - Self::GAMUT_CHROMA_TOLERANCE
+    }
+
+    /// The highest chroma sRGB can show for the given hue and tone.
+    #[must_use]
+    pub fn max_chroma(hue: f64, tone: f64) -> f64 {
+        Self::new(hue, 200.0, tone).chroma()
+    }
+
+    /// Interpolates between two colors. Hue travels along the shortest arc, chroma and tone
+    /// linearly. `t` is clamped to `0.0..=1.0`.
+    #[must_use]
+    pub fn lerp(a: &Self, b: &Self, t: f64) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let hue_delta = MathUtils::difference_degrees(a.hue, b.hue)
+            * MathUtils::rotation_direction(a.hue, b.hue);
+        Self::new(
+            MathUtils::sanitize_degrees_double(hue_delta.mul_add(t, a.hue)),
+            MathUtils::lerp(a.chroma, b.chroma, t),
+            MathUtils::lerp(a.tone, b.tone, t),
+        )
     }
 
     #[must_use]
@@ -98,6 +222,20 @@ impl Hct {
         self.argb
     }
 
+    /// Like [`Hct::to_argb`], but also reports whether the chroma this color was created with had
+    /// to be lowered to fit in sRGB. Colors created from an ARGB value are never reduced.
+    #[must_use]
+    pub fn to_argb_checked(&self) -> (Argb, bool) {
+        let reduced = self.requested_chroma - self.chroma > Self::GAMUT_CHROMA_TOLERANCE;
+        (self.argb, reduced)
+    }
+
+    /// CAM16 representation of this color in default viewing conditions.
+    #[must_use]
+    pub fn to_cam16(&self) -> Cam16 {
+        Cam16::from_argb(self.argb)
+    }
+
     /// Set the hue of this color. Chroma may decrease because chroma has a different maximum for any
     /// given hue and tone.
     ///
@@ -105,7 +243,8 @@ impl Hct {
     ///
     /// * `new_hue`: 0 <= `new_hue` < 360; invalid values are corrected.
     pub fn set_hue(&mut self, new_hue: f64) {
-        self.set_internal_state(HctSolver::solve_to_argb(new_hue, self.chroma, self.tone));
+        let chroma = self.chroma;
+        self.set_internal_state(HctSolver::solve_to_argb(new_hue, chroma, self.tone), chroma);
     }
 
     /// Set the chroma of this color. Chroma may decrease because chroma has a different maximum for
@@ -115,7 +254,10 @@ impl Hct {
     ///
     /// * `new_chroma`: 0 <= `new_chroma` < ?
     pub fn set_chroma(&mut self, new_chroma: f64) {
-        self.set_internal_state(HctSolver::solve_to_argb(self.hue, new_chroma, self.tone));
+        self.set_internal_state(
+            HctSolver::solve_to_argb(self.hue, new_chroma, self.tone),
+            new_chroma,
+        );
     }
 
     /// Set the tone of this color. Chroma may decrease because chroma has a different maximum for any
@@ -125,15 +267,83 @@ impl Hct {
     ///
     /// * `new_tone`: 0 <= `new_tone` <= 100; invalid values are corrected.
     pub fn set_tone(&mut self, new_tone: f64) {
-        self.set_internal_state(HctSolver::solve_to_argb(self.hue, self.chroma, new_tone));
+        let chroma = self.chroma;
+        self.set_internal_state(HctSolver::solve_to_argb(self.hue, chroma, new_tone), chroma);
     }
 
-    fn set_internal_state(&mut self, argb: Argb) {
-        self.argb = argb;
-        let cam = Cam16::from_argb(argb);
-        self.hue = cam.hue;
-        self.chroma = cam.chroma;
-        self.tone = argb.lstar();
+    /// Returns a copy of this color with its hue replaced. See [`Hct::set_hue`].
+    #[must_use]
+    pub fn with_hue(&self, hue: f64) -> Self {
+        let mut hct = *self;
+        hct.set_hue(hue);
+        hct
+    }
+
+    /// Returns a copy of this color with its chroma replaced. See [`Hct::set_chroma`].
+    #[must_use]
+    pub fn with_chroma(&self, chroma: f64) -> Self {
+        let mut hct = *self;
+        hct.set_chroma(chroma);
+        hct
+    }
+
+    /// Returns a copy of this color with its tone replaced. See [`Hct::set_tone`].
+    #[must_use]
+    pub fn with_tone(&self, tone: f64) -> Self {
+        let mut hct = *self;
+        hct.set_tone(tone);
+        hct
+    }
+
+    /// A cheap distance between two colors, computed directly on their HCT coordinates.
+    ///
+    /// Tone and chroma differences count linearly. The hue difference counts as the chord between
+    /// the two hues on a circle whose radius is the geometric mean of the chromas, so hue matters
+    /// little for muted colors and not at all at zero chroma. sRGB greys aren't quite at zero: in
+    /// CAM16 they keep a chroma of about 2, so their hue still counts a little. Together this is
+    /// the Euclidean distance in HCT's cylindrical space. It avoids the CAM16-UCS conversion of
+    /// [`Cam16::distance`], at the cost of being less perceptually uniform.
+    #[must_use]
+    pub fn perceptual_distance(&self, other: &Self) -> f64 {
+        let d_tone = self.tone - other.tone;
+        let d_chroma = self.chroma - other.chroma;
+        let half_hue = (MathUtils::difference_degrees(self.hue, other.hue) / 2.0).to_radians();
+        let d_hue = 2.0 * (self.chroma * other.chroma).sqrt() * half_hue.sin();
+        d_hue
+            .mul_add(d_hue, d_tone.mul_add(d_tone, d_chroma * d_chroma))
+            .sqrt()
+    }
+
+    /// Returns a copy of this color that is `amount` L* points lighter, clamped to tone 100. Hue
+    /// and chroma are kept, except that chroma is lowered when it doesn't fit the new tone.
+    ///
+    /// A negative `amount` darkens; see [`Hct::darken`].
+    #[must_use]
+    pub fn lighten(&self, amount: f64) -> Self {
+        self.with_tone((self.tone + amount).clamp(0.0, 100.0))
+    }
+
+    /// Returns a copy of this color that is `amount` L* points darker, clamped to tone 0. See
+    /// [`Hct::lighten`].
+    #[must_use]
+    pub fn darken(&self, amount: f64) -> Self {
+        self.lighten(-amount)
+    }
+
+    /// Returns a copy of this color with its hue rotated by `degrees`, wrapping at 360. Rotating by
+    /// a whole number of turns returns the color unchanged.
+    #[must_use]
+    #[allow(clippy::float_cmp)]
+    pub fn rotate_hue(&self, degrees: f64) -> Self {
+        let degrees = degrees.rem_euclid(360.0);
+        if degrees == 0.0 {
+            return *self;
+        }
+        self.with_hue(MathUtils::sanitize_degrees_double(self.hue + degrees))
+    }
+
+    fn set_internal_state(&mut self, argb: Argb, requested_chroma: f64) {
+        *self = Self::new_internal(argb, Some(requested_chroma));
     }
 
     /// Translate a color into different `ViewingConditions`.
@@ -185,16 +395,72 @@ impl Hct {
     pub fn is_cyan(hue: f64) -> bool {
         (170.0..207.0).contains(&hue)
     }
+
+    /// Hues in `[0, 40)` or `[345, 360)`.
+    #[must_use]
+    pub fn is_red(hue: f64) -> bool {
+        (0.0..40.0).contains(&hue) || (345.0..360.0).contains(&hue)
+    }
+
+    /// Hues in `[40, 105)`.
+    #[must_use]
+    pub fn is_orange(hue: f64) -> bool {
+        (40.0..105.0).contains(&hue)
+    }
+
+    /// Hues in `[125, 170)`.
+    #[must_use]
+    pub fn is_green(hue: f64) -> bool {
+        (125.0..170.0).contains(&hue)
+    }
+
+    /// Hues in `[270, 310)`.
+    #[must_use]
+    pub fn is_purple(hue: f64) -> bool {
+        (270.0..310.0).contains(&hue)
+    }
+
+    /// Hues in `[310, 345)`.
+    #[must_use]
+    pub fn is_magenta(hue: f64) -> bool {
+        (310.0..345.0).contains(&hue)
+    }
+
+    /// Classifies this color's hue using the `is_*` predicates above.
+    ///
+    /// The predicates keep the ranges the color specs branch on, which leave `[207, 250)` between
+    /// cyan and blue uncovered; those hues are reported as [`HueFamily::Blue`].
+    #[must_use]
+    pub fn hue_family(&self) -> HueFamily {
+        let hue = MathUtils::sanitize_degrees_double(self.hue);
+        if Self::is_red(hue) {
+            HueFamily::Red
+        } else if Self::is_orange(hue) {
+            HueFamily::Orange
+        } else if Self::is_yellow(hue) {
+            HueFamily::Yellow
+        } else if Self::is_green(hue) {
+            HueFamily::Green
+        } else if Self::is_cyan(hue) {
+            HueFamily::Cyan
+        } else if Self::is_purple(hue) {
+            HueFamily::Purple
+        } else if Self::is_magenta(hue) {
+            HueFamily::Magenta
+        } else {
+            HueFamily::Blue
+        }
+    }
 }
 
+/// Formats the color as `hct(hue, chroma, tone)`, each rounded to one decimal. Use `{:?}` to see
+/// every field at full precision.
 impl fmt::Display for Hct {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(
             f,
-            "HCT({}, {}, {})",
-            self.hue.round(),
-            self.chroma.round(),
-            self.tone.round()
+            "hct({:.1}, {:.1}, {:.1})",
+            self.hue, self.chroma, self.tone
         )
     }
 }
@@ -245,6 +511,20 @@ mod tests {
         assert!((hct.tone() - hct_vc.tone()).abs() < 1.0);
     }
 
+    #[test]
+    fn test_hct_from_argb_in() {
+        let argb = Argb(0xFF4285F4);
+        let default_hct = Hct::from_argb(argb);
+        let default_in = Hct::from_argb_in(argb, &ViewingConditions::default());
+        assert_eq!(default_in, default_hct);
+
+        let dim = ViewingConditions::builder().surround(0.0).build();
+        let dim_hct = Hct::from_argb_in(argb, &dim);
+        assert_eq!(dim_hct.to_argb(), argb);
+        assert!((dim_hct.tone() - default_hct.tone()).abs() < 1e-9);
+        assert!((dim_hct.chroma() - default_hct.chroma()).abs() > 0.5);
+    }
+
     #[test]
     fn test_hct_hue_checks() {
         assert!(Hct::is_blue(260.0));
@@ -253,6 +533,156 @@ mod tests {
         assert!(Hct::is_cyan(180.0));
     }
 
+    #[test]
+    fn test_from_argb_with_white_point() {
+        let grey = Argb(0xFF808080);
+        let d65 = Hct::from_argb_with_white_point(grey, WhitePoint::D65);
+        assert_eq!(d65, Hct::from_argb(grey));
+
+        // Under a warmer reference white, a display grey looks noticeably blue.
+        let d50 = Hct::from_argb_with_white_point(grey, WhitePoint::D50);
+        assert!(d50.chroma() > d65.chroma() + 5.0, "{d50} vs {d65}");
+        assert!(MathUtils::difference_degrees(d50.hue(), d65.hue()) > 20.0);
+        assert!((d50.tone() - d65.tone()).abs() < 1e-9);
+        assert_eq!(d50.to_argb(), grey);
+    }
+
+    #[test]
+    fn test_from_argb_slice_matches_scalar() {
+        let mut pixels: Vec<Argb> = (0..4096u32)
+            .map(|i| Argb(0xFF00_0000 | (i.wrapping_mul(2_654_435_761) & 0x00FF_FFFF)))
+            .collect();
+        pixels.extend([Argb(0xFF4285F4); 16]);
+        let scalar: Vec<Hct> = pixels.iter().map(|&p| Hct::from_argb(p)).collect();
+        assert_eq!(Hct::from_argb_slice(&pixels), scalar);
+        assert!(Hct::from_argb_slice(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_with_axis() {
+        let hct = Hct::from_argb(Argb(0xFF4285F4));
+
+        let toned = hct.with_tone(50.0);
+        assert!((toned.tone() - 50.0).abs() < 0.5, "{}", toned.tone());
+        assert!((toned.hue() - hct.hue()).abs() < 1.0);
+        assert_eq!(hct, Hct::from_argb(Argb(0xFF4285F4)));
+
+        let muted = hct.with_chroma(10.0);
+        assert!((muted.chroma() - 10.0).abs() < 0.5, "{}", muted.chroma());
+
+        let hued = hct.with_hue(120.0);
+        assert!((hued.hue() - 120.0).abs() < 1.0, "{}", hued.hue());
+
+        assert_eq!(hct.rotate_hue(360.0), hct);
+        assert_eq!(hct.rotate_hue(-720.0), hct);
+        let rotated = hct.rotate_hue(180.0);
+        let expected = MathUtils::sanitize_degrees_double(hct.hue() + 180.0);
+        assert!(MathUtils::difference_degrees(rotated.hue(), expected) < 1.0);
+    }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_perceptual_distance() {
+        let hct = Hct::from_argb(Argb(0xFF4285F4));
+        assert_eq!(hct.perceptual_distance(&hct), 0.0);
+
+        let shifted = |tone_delta: f64| Hct {
+            tone: hct.tone() + tone_delta,
+            ..hct
+        };
+        assert!((hct.perceptual_distance(&shifted(5.0)) - 5.0).abs() < 1e-9);
+        assert!((hct.perceptual_distance(&shifted(-10.0)) - 10.0).abs() < 1e-9);
+
+        let opposite = Hct {
+            hue: MathUtils::sanitize_degrees_double(hct.hue() + 180.0),
+            ..hct
+        };
+        let expected = 2.0 * hct.chroma();
+        assert!((hct.perceptual_distance(&opposite) - expected).abs() < 1e-9);
+
+        // Hue doesn't count at zero chroma, but sRGB greys have a little chroma in CAM16.
+        let grey = Hct::from_argb(Argb(0xFF777777));
+        let achromatic = Hct {
+            chroma: 0.0,
+            ..grey
+        };
+        let rotated = Hct {
+            hue: MathUtils::sanitize_degrees_double(grey.hue() + 123.0),
+            ..achromatic
+        };
+        assert!(achromatic.perceptual_distance(&rotated) < 1e-9);
+        assert!(grey.chroma() > 1.0);
+        let rotated_grey = Hct {
+            hue: rotated.hue(),
+            ..grey
+        };
+        assert!(grey.perceptual_distance(&rotated_grey) > 1.0);
+    }
+
+    #[test]
+    fn test_lighten_darken() {
+        let hct = Hct::from_argb(Argb(0xFF4285F4));
+
+        let lighter = hct.lighten(10.0);
+        assert!(
+            (lighter.tone() - hct.tone() - 10.0).abs() < 0.5,
+            "{}",
+            lighter.tone()
+        );
+        assert!(MathUtils::difference_degrees(lighter.hue(), hct.hue()) < 1.0);
+
+        let darker = hct.darken(10.0);
+        assert!(
+            (hct.tone() - darker.tone() - 10.0).abs() < 0.5,
+            "{}",
+            darker.tone()
+        );
+        assert!((darker.chroma() - hct.chroma()).abs() < 1.0);
+
+        assert_eq!(hct.lighten(200.0).to_argb(), Argb(0xFFFFFFFF));
+        assert_eq!(hct.darken(200.0).to_argb(), Argb(0xFF000000));
+    }
+
+    #[test]
+    fn test_hue_family_boundaries() {
+        let cases = [
+            (0.0, HueFamily::Red),
+            (39.9, HueFamily::Red),
+            (40.0, HueFamily::Orange),
+            (104.9, HueFamily::Orange),
+            (105.0, HueFamily::Yellow),
+            (125.0, HueFamily::Green),
+            (169.9, HueFamily::Green),
+            (170.0, HueFamily::Cyan),
+            (207.0, HueFamily::Blue),
+            (250.0, HueFamily::Blue),
+            (270.0, HueFamily::Purple),
+            (309.9, HueFamily::Purple),
+            (310.0, HueFamily::Magenta),
+            (344.9, HueFamily::Magenta),
+            (345.0, HueFamily::Red),
+        ];
+        for (hue, expected) in cases {
+            // Set the hue directly so gamut mapping cannot move it off the boundary.
+            let hct = Hct {
+                hue,
+                ..Hct::new(hue, 0.0, 50.0)
+            };
+            assert_eq!(hct.hue_family(), expected, "hue {hue}");
+        }
+        assert_eq!(
+            Hct::from_argb(Argb(0xFFFF0000)).hue_family(),
+            HueFamily::Red
+        );
+        assert!(Hct::is_red(359.9));
+        assert!(!Hct::is_red(40.0));
+        assert!(Hct::is_orange(40.0));
+        assert!(Hct::is_green(125.0));
+        assert!(Hct::is_purple(270.0));
+        assert!(Hct::is_magenta(310.0));
+        assert!(!Hct::is_magenta(345.0));
+    }
+
     #[test]
     fn test_hct_roundtrip_in_gamut() {
         let hue = 67.0;
@@ -284,4 +714,78 @@ mod tests {
         // The resulting ARGB should be #B26C00
         assert_eq!(format!("{:X}", hct.to_argb().0), "FFB26C00");
     }
+
+    #[test]
+    fn test_hct_gamut() {
+        assert!(Hct::is_in_gamut(67.0, 20.0, 52.0));
+        assert!(!Hct::is_in_gamut(67.0, 91.0, 52.0));
+
+        let max_chroma = Hct::max_chroma(67.0, 52.0);
+        assert!((max_chroma - 49.2).abs() < 1.0);
+        assert!(Hct::is_in_gamut(67.0, max_chroma, 52.0));
+
+        // Saturated red loses most of its chroma near white
+        assert!(Hct::max_chroma(27.0, 50.0) > Hct::max_chroma(27.0, 99.0));
+    }
+
+    #[test]
+    fn test_lerp_takes_shortest_hue_arc() {
+        let a = Hct::new(350.0, 20.0, 50.0);
+        let b = Hct::new(10.0, 20.0, 50.0);
+        let mid = Hct::lerp(&a, &b, 0.5);
+        assert!(MathUtils::difference_degrees(mid.hue(), 0.0) < 2.0);
+        assert!((mid.tone() - 50.0).abs() < 0.5);
+
+        assert_eq!(Hct::lerp(&a, &b, -1.0), Hct::lerp(&a, &b, 0.0));
+        assert_eq!(Hct::lerp(&a, &b, 2.0), Hct::lerp(&a, &b, 1.0));
+    }
+
+    #[test]
+    fn test_hash_and_eq() {
+        use std::collections::HashMap;
+        use std::hash::BuildHasher;
+
+        let a = Hct::new(120.0, 40.0, 60.0);
+        let b = Hct::new(120.0, 40.0, 60.0);
+        assert_eq!(a, b);
+
+        let state = std::hash::RandomState::new();
+        assert_eq!(state.hash_one(a), state.hash_one(b));
+
+        let mut cache = HashMap::new();
+        cache.insert(a, "cached");
+        assert_eq!(cache.get(&b), Some(&"cached"));
+        assert_eq!(cache.get(&Hct::new(121.0, 40.0, 60.0)), None);
+    }
+
+    #[test]
+    fn test_to_argb_checked() {
+        let saturated = Hct::new(260.0, 100.0, 95.0);
+        let (argb, reduced) = saturated.to_argb_checked();
+        assert_eq!(argb, saturated.to_argb());
+        assert!(reduced);
+
+        assert!(!Hct::new(260.0, 10.0, 50.0).to_argb_checked().1);
+        assert!(!Hct::from_argb(Argb(0xff0000ff)).to_argb_checked().1);
+    }
+
+    #[test]
+    fn test_display_and_debug() {
+        let red = Hct::from_argb(Argb(0xFFFF0000));
+        assert_eq!(red.to_string(), "hct(27.4, 113.4, 53.2)");
+        assert_eq!(
+            Hct::from_argb(Argb(0xFF0000FF)).to_string(),
+            "hct(282.8, 87.2, 32.3)"
+        );
+
+        let debug = format!("{red:?}");
+        for label in [
+            "hue: 27.4",
+            "chroma: 113.3",
+            "tone: 53.2",
+            "argb: Argb(#FF0000)",
+        ] {
+            assert!(debug.contains(label), "{debug} misses {label}");
+        }
+    }
 }
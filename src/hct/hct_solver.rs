@@ -4,6 +4,63 @@ use crate::utils::color_utils::Argb;
 use crate::utils::color_utils::ColorUtils;
 use crate::utils::math_utils::MathUtils;
 
+/// A fixed-size, direct-mapped cache of gamut boundary colors, enabled by the `solver-cache`
+/// feature.
+///
+/// The boundary only depends on hue and tone, so it is shared by every requested chroma that lands
+/// out of gamut. Keys are the exact bits of (hue in radians, Y) rather than quantized values: a
+/// quantized key would hand back the boundary of a nearby hue or tone and change the solver's
+/// output, while palettes (the case this is for) ask for the exact same hue over and over anyway.
+///
+/// Each key maps to one slot and a new entry simply replaces the old one, so memory stays at
+/// `1 << SLOT_BITS` entries. Slots are only ever `try_lock`ed: a contended slot is treated as a
+/// miss, so parallel solvers never wait on each other.
+#[cfg(feature = "solver-cache")]
+mod boundary_cache {
+    use crate::utils::color_utils::Argb;
+    use std::sync::{Mutex, OnceLock};
+
+    const SLOT_BITS: u32 = 12;
+
+    type Slot = Mutex<Option<(u64, u64, Argb)>>;
+
+    static SLOTS: OnceLock<Box<[Slot]>> = OnceLock::new();
+
+    fn slots() -> &'static [Slot] {
+        SLOTS.get_or_init(|| (0..1 << SLOT_BITS).map(|_| Mutex::new(None)).collect())
+    }
+
+    pub(super) fn get_or_insert_with(
+        hue_radians: f64,
+        y: f64,
+        solve: impl FnOnce() -> Argb,
+    ) -> Argb {
+        let (hue_bits, y_bits) = (hue_radians.to_bits(), y.to_bits());
+        let hash = (hue_bits ^ y_bits.rotate_left(32)).wrapping_mul(0x9E37_79B9_7F4A_7C15);
+        let slot = &slots()[(hash >> (64 - SLOT_BITS)) as usize];
+
+        if let Ok(entry) = slot.try_lock()
+            && let Some((cached_hue, cached_y, argb)) = *entry
+            && (cached_hue, cached_y) == (hue_bits, y_bits)
+        {
+            return argb;
+        }
+        let argb = solve();
+        if let Ok(mut entry) = slot.try_lock() {
+            *entry = Some((hue_bits, y_bits, argb));
+        }
+        argb
+    }
+
+    pub(super) fn clear() {
+        for slot in slots() {
+            if let Ok(mut entry) = slot.lock() {
+                *entry = None;
+            }
+        }
+    }
+}
+
 /// A class that solves the HCT equation.
 pub struct HctSolver;
 
@@ -583,8 +640,28 @@ impl HctSolver {
         if let Some(ea) = exact_answer {
             return ea;
         }
-        let lin_rgb = Self::bisect_to_limit(y, hue_radians);
-        Argb::from_linrgb(lin_rgb)
+        Self::boundary_argb(y, hue_radians)
+    }
+
+    /// The most chromatic sRGB color with the given Y and hue.
+    #[cfg(not(feature = "solver-cache"))]
+    fn boundary_argb(y: f64, hue_radians: f64) -> Argb {
+        Argb::from_linrgb(Self::bisect_to_limit(y, hue_radians))
+    }
+
+    /// The most chromatic sRGB color with the given Y and hue, memoized across calls.
+    #[cfg(feature = "solver-cache")]
+    fn boundary_argb(y: f64, hue_radians: f64) -> Argb {
+        boundary_cache::get_or_insert_with(hue_radians, y, || {
+            Argb::from_linrgb(Self::bisect_to_limit(y, hue_radians))
+        })
+    }
+
+    /// Empties the gamut boundary cache used by [`HctSolver::solve_to_argb`]. The cache is
+    /// bounded, so this is never needed to free memory; it's useful for measuring cold solves.
+    #[cfg(feature = "solver-cache")]
+    pub fn clear_boundary_cache() {
+        boundary_cache::clear();
     }
 
     /// Finds an sRGB color with the given hue, chroma, and L*, if possible.
@@ -624,4 +701,25 @@ mod tests {
         assert_eq!(gray.green(), gray.blue());
         assert!((i32::from(gray.red()) - 119).abs() <= 1);
     }
+
+    #[test]
+    #[cfg(feature = "solver-cache")]
+    fn test_boundary_cache_matches_uncached() {
+        let uncached = |hue: f64, tone: f64| {
+            let y = ColorUtils::y_from_lstar(tone);
+            let hue_radians = MathUtils::sanitize_degrees_double(hue).to_radians();
+            Argb::from_linrgb(HctSolver::bisect_to_limit(y, hue_radians))
+        };
+
+        HctSolver::clear_boundary_cache();
+        for hue in (0..360).step_by(15) {
+            for tone in (5..100).step_by(10) {
+                let (hue, tone) = (f64::from(hue), f64::from(tone));
+                let cold = HctSolver::solve_to_argb(hue, 200.0, tone);
+                let warm = HctSolver::solve_to_argb(hue, 200.0, tone);
+                assert_eq!(cold, warm);
+                assert_eq!(warm, uncached(hue, tone));
+            }
+        }
+    }
 }
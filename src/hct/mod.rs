@@ -20,6 +20,6 @@ pub mod hct_solver;
 pub mod viewing_conditions;
 
 pub use cam16::Cam16;
-pub use hct_color::Hct;
+pub use hct_color::{Hct, HueFamily};
 pub use hct_solver::HctSolver;
-pub use viewing_conditions::ViewingConditions;
+pub use viewing_conditions::{ViewingConditions, WhitePoint};
@@ -1,8 +1,33 @@
 use crate::hct::cam16::Cam16;
 use crate::utils::color_utils::ColorUtils;
 use crate::utils::math_utils::MathUtils;
+use bon::bon;
 use std::f64::consts::PI;
 
+/// Reference white of the viewing environment, used to build [`ViewingConditions`].
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum WhitePoint {
+    /// Standard daylight, used by sRGB displays. The default.
+    #[default]
+    D65,
+    /// Horizon daylight, the usual reference for print.
+    D50,
+    /// Any white point, as XYZ with Y = 100.
+    Custom([f64; 3]),
+}
+
+impl WhitePoint {
+    /// The white point in the XYZ color space, scaled so that Y is 100.
+    #[must_use]
+    pub const fn xyz(&self) -> [f64; 3] {
+        match self {
+            Self::D65 => ColorUtils::white_point_d65(),
+            Self::D50 => [96.422, 100.0, 82.521],
+            Self::Custom(xyz) => *xyz,
+        }
+    }
+}
+
 /// In traditional color spaces, a color can be identified solely by the observer's measurement of
 /// the color. Color appearance models such as CAM16 also use information about the environment where
 /// the color was observed, known as the viewing conditions.
@@ -134,6 +159,38 @@ impl ViewingConditions {
     }
 }
 
+#[bon]
+impl ViewingConditions {
+    /// Builds `ViewingConditions` for a non-default environment. Parameters that aren't set keep
+    /// their sRGB defaults, see [`ViewingConditions::make`] for what each of them means.
+    #[builder]
+    pub fn new(
+        /// White point, measured in the XYZ color space. Defaults to D65.
+        #[builder(default = ColorUtils::white_point_d65())]
+        white_point: [f64; 3],
+        /// The luminance of the adapting field. Defaults to 200 lux.
+        #[builder(default = 200.0 / PI * ColorUtils::y_from_lstar(50.0) / 100.0)]
+        adapting_luminance: f64,
+        /// The lightness of the area surrounding the color, as L*. Defaults to `50.0`.
+        #[builder(default = 50.0)]
+        background_lstar: f64,
+        /// Lighting surrounding the color, from `0.0` (dark) to `2.0` (average). Defaults to `2.0`.
+        #[builder(default = 2.0)]
+        surround: f64,
+        /// Whether the eye discounts the tint of the ambient lighting. Defaults to `false`.
+        #[builder(default = false)]
+        discounting_illuminant: bool,
+    ) -> Self {
+        Self::make(
+            white_point,
+            adapting_luminance,
+            background_lstar,
+            surround,
+            discounting_illuminant,
+        )
+    }
+}
+
 impl Default for ViewingConditions {
     fn default() -> Self {
         Self::default_with_background_lstar(50.0)
@@ -152,4 +209,28 @@ mod tests {
         // Default aw is roughly 29.981
         assert!((vc.aw - 29.981).abs() < 0.001);
     }
+
+    #[test]
+    fn test_white_point() {
+        assert_eq!(WhitePoint::default().xyz(), ColorUtils::white_point_d65());
+        assert_eq!(
+            ViewingConditions::builder()
+                .white_point(WhitePoint::D65.xyz())
+                .build(),
+            ViewingConditions::default()
+        );
+        assert_eq!(WhitePoint::Custom([1.0, 2.0, 3.0]).xyz(), [1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn test_builder_defaults() {
+        assert_eq!(
+            ViewingConditions::builder().build(),
+            ViewingConditions::default()
+        );
+        assert_eq!(
+            ViewingConditions::builder().background_lstar(30.0).build(),
+            ViewingConditions::default_with_background_lstar(30.0)
+        );
+    }
 }
@@ -1,6 +1,64 @@
 use crate::contrast::contrast_utils::Contrast;
+use crate::dynamic::dynamic_color::DynamicColor;
+use crate::dynamic::dynamic_scheme::DynamicScheme;
+use crate::dynamic::material_dynamic_colors::MaterialDynamicColors;
 use crate::hct::Hct;
+use crate::helpers::ContrastReport;
 use crate::utils::color_utils::Argb;
+use std::sync::Arc;
+
+/// WCAG AA contrast ratio for normal text.
+const AA_RATIO: f64 = 4.5;
+
+type RoleGetter = fn(&MaterialDynamicColors) -> Arc<DynamicColor>;
+
+/// Standard foreground/background role pairs checked by [`audit`].
+const AUDITED_PAIRS: &[(RoleGetter, RoleGetter)] = &[
+    (
+        MaterialDynamicColors::on_primary,
+        MaterialDynamicColors::primary,
+    ),
+    (
+        MaterialDynamicColors::on_primary_container,
+        MaterialDynamicColors::primary_container,
+    ),
+    (
+        MaterialDynamicColors::on_secondary,
+        MaterialDynamicColors::secondary,
+    ),
+    (
+        MaterialDynamicColors::on_secondary_container,
+        MaterialDynamicColors::secondary_container,
+    ),
+    (
+        MaterialDynamicColors::on_tertiary,
+        MaterialDynamicColors::tertiary,
+    ),
+    (
+        MaterialDynamicColors::on_tertiary_container,
+        MaterialDynamicColors::tertiary_container,
+    ),
+    (
+        MaterialDynamicColors::on_error,
+        MaterialDynamicColors::error,
+    ),
+    (
+        MaterialDynamicColors::on_error_container,
+        MaterialDynamicColors::error_container,
+    ),
+    (
+        MaterialDynamicColors::on_surface,
+        MaterialDynamicColors::surface,
+    ),
+    (
+        MaterialDynamicColors::on_surface_variant,
+        MaterialDynamicColors::surface,
+    ),
+    (
+        MaterialDynamicColors::inverse_on_surface,
+        MaterialDynamicColors::inverse_surface,
+    ),
+];
 
 /// Returns the contrast ratio of two colors.
 #[must_use]
@@ -42,6 +100,50 @@ pub fn darker_tone_unsafe(color: Argb, ratio: f64) -> Argb {
     Hct::new(hct.hue(), hct.chroma(), new_tone).to_argb()
 }
 
+/// Returns the candidate with the highest contrast ratio against `background`, or `None` if
+/// `candidates` is empty. Ties go to the earlier candidate.
+#[must_use]
+pub fn best_foreground(background: Argb, candidates: &[Argb]) -> Option<Argb> {
+    candidates
+        .iter()
+        .copied()
+        .map(|candidate| (candidate, get_contrast_ratio(candidate, background)))
+        .reduce(|best, next| if next.1 > best.1 { next } else { best })
+        .map(|(candidate, _)| candidate)
+}
+
+/// Returns white or black, whichever contrasts more with `background`.
+#[must_use]
+pub fn black_or_white(background: Argb) -> Argb {
+    let (white, black) = (Argb(0xFFFFFFFF), Argb(0xFF000000));
+    if get_contrast_ratio(white, background) >= get_contrast_ratio(black, background) {
+        white
+    } else {
+        black
+    }
+}
+
+/// Measures the contrast of the standard on-color/color role pairs of `scheme`, such as
+/// `on_primary` on `primary` and `on_surface` on `surface`.
+#[must_use]
+pub fn audit(scheme: &DynamicScheme) -> Vec<ContrastReport> {
+    let colors = MaterialDynamicColors::new();
+    AUDITED_PAIRS
+        .iter()
+        .map(|(foreground, background)| {
+            let (foreground, background) = (foreground(&colors), background(&colors));
+            let ratio =
+                get_contrast_ratio(scheme.get_argb(&foreground), scheme.get_argb(&background));
+            ContrastReport {
+                foreground: foreground.name.clone(),
+                background: background.name.clone(),
+                ratio,
+                passes_aa: ratio >= AA_RATIO,
+            }
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -97,4 +199,40 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_audit_tonal_spot() {
+        let scheme = crate::scheme::SchemeTonalSpot::builder(
+            Argb::from_hex("#4285f4").expect("valid hex"),
+            false,
+            0.0,
+        )
+        .build();
+        let reports = audit(&scheme);
+        assert_eq!(reports.len(), 11);
+        for report in &reports {
+            assert!(report.passes_aa, "{report:?}");
+        }
+        assert_eq!(reports[0].foreground, "on_primary");
+        assert_eq!(reports[0].background, "primary");
+    }
+
+    #[test]
+    fn test_best_foreground() {
+        let dark_blue = Argb(0xFF0D1B4C);
+        let pale_yellow = Argb(0xFFFFF9C4);
+        assert_eq!(black_or_white(dark_blue), Argb(0xFFFFFFFF));
+        assert_eq!(black_or_white(pale_yellow), Argb(0xFF000000));
+
+        let candidates = [Argb(0xFF777777), Argb(0xFF1A1A1A), Argb(0xFFEEEEEE)];
+        assert_eq!(
+            best_foreground(pale_yellow, &candidates),
+            Some(Argb(0xFF1A1A1A))
+        );
+        assert_eq!(
+            best_foreground(dark_blue, &candidates),
+            Some(Argb(0xFFEEEEEE))
+        );
+        assert_eq!(best_foreground(dark_blue, &[]), None);
+    }
 }
@@ -5,3 +5,20 @@ pub enum ThemeGenerationError {
     #[error("couldn't extract color from image")]
     CouldNotExtractColorFromImage,
 }
+
+/// Error returned by [`from_material_json`](crate::from_material_json).
+#[cfg(feature = "serde")]
+#[derive(Debug, Error)]
+pub enum ThemeParseError {
+    #[error("invalid theme JSON: {0}")]
+    InvalidJson(#[from] serde_json::Error),
+
+    #[error("theme JSON has no `{0}` field")]
+    MissingField(&'static str),
+
+    #[error("invalid color in `{field}`: {source}")]
+    InvalidColor {
+        field: String,
+        source: crate::utils::error::ColorParseError,
+    },
+}
@@ -1,9 +1,7 @@
 use crate::dynamic::color_spec::{Platform, SpecVersion};
 use crate::dynamic::variant::Variant;
 use crate::helpers::error::ThemeGenerationError;
-use crate::quantize::Quantizer;
-use crate::quantize::QuantizerCelebi;
-use crate::score::score_colors::Score;
+use crate::helpers::theme_helpers::seed_candidates;
 use crate::utils::color_utils::Argb;
 use crate::{MaterializedTheme, theme_from_color};
 use image::DynamicImage;
@@ -33,12 +31,7 @@ pub fn extract_image_colors(
             Argb::from_rgb(r, g, b)
         })
         .collect();
-    let mut celebi = QuantizerCelebi::new();
-    let result = celebi.quantize(&pixels, quantize_max_colors);
-
-    Score::score(&result.color_to_count)
-        .desired_count(desired_colors)
-        .call()
+    seed_candidates(&pixels, quantize_max_colors, desired_colors)
 }
 
 /// Generate multiple themes from a source image.
@@ -0,0 +1,264 @@
+use crate::dynamic::color_spec::SpecVersion;
+use crate::dynamic::variant::Variant;
+use crate::helpers::{MaterializedScheme, MaterializedTheme, ThemeParseError, theme_from_color};
+use crate::palettes::tonal_palette::TonalPalette;
+use crate::utils::color_utils::Argb;
+use serde_json::{Map, Value, json};
+
+/// The tones the Material Theme Builder exports for every palette.
+const MATERIAL_JSON_TONES: [i32; 18] = [
+    0, 5, 10, 15, 20, 25, 30, 35, 40, 50, 60, 70, 80, 90, 95, 98, 99, 100,
+];
+
+/// Exports a theme in the JSON shape of the web Material Theme Builder.
+///
+/// The output has the builder's top-level `seed`, `coreColors`, `extendedColors`, `schemes` and
+/// `palettes` keys, with role names in camelCase and colors as `#RRGGBB`. Only the `light` and
+/// `dark` schemes are written, at the theme's own contrast level; the builder's extra
+/// medium/high contrast schemes have no counterpart in a [`MaterializedTheme`].
+#[must_use]
+pub fn to_material_json(theme: &MaterializedTheme) -> String {
+    let light = &theme.schemes.light;
+    let extended_colors: Vec<Value> = theme
+        .custom_colors
+        .iter()
+        .map(|custom| {
+            json!({
+                "name": custom.name,
+                "color": custom.value.to_hex(),
+                "description": "",
+                "harmonized": custom.blend,
+            })
+        })
+        .collect();
+
+    let value = json!({
+        "seed": theme.source_color.to_hex(),
+        "coreColors": { "primary": theme.source_color.to_hex() },
+        "extendedColors": extended_colors,
+        "schemes": {
+            "light": scheme_json(light),
+            "dark": scheme_json(&theme.schemes.dark),
+        },
+        "palettes": {
+            "primary": palette_json(&light.primary_palette),
+            "secondary": palette_json(&light.secondary_palette),
+            "tertiary": palette_json(&light.tertiary_palette),
+            "neutral": palette_json(&light.neutral_palette),
+            "neutral-variant": palette_json(&light.neutral_variant_palette),
+        },
+    });
+    serde_json::to_string_pretty(&value).expect("serializing a JSON value can't fail")
+}
+
+/// Reads a theme exported by the web Material Theme Builder (or by [`to_material_json`]).
+///
+/// Schemes and palettes are derived data, so only the `seed` (falling back to
+/// `coreColors.primary`) and the `extendedColors` are read. The theme is regenerated the way the
+/// Material Theme Builder does it: [`Variant::TonalSpot`] at standard contrast with the 2021
+/// spec. Extended colors without a `harmonized` flag are harmonized, as in the builder.
+///
+/// # Errors
+/// Returns a [`ThemeParseError`] if the input isn't JSON, has no seed, or contains a color that
+/// isn't a valid hex string.
+pub fn from_material_json(json: &str) -> Result<MaterializedTheme, ThemeParseError> {
+    let value: Value = serde_json::from_str(json)?;
+    let seed = value
+        .get("seed")
+        .or_else(|| value.pointer("/coreColors/primary"))
+        .and_then(Value::as_str)
+        .ok_or(ThemeParseError::MissingField("seed"))?;
+    let seed = parse_color("seed", seed)?;
+
+    let mut theme = theme_from_color(seed)
+        .variant(Variant::TonalSpot)
+        .spec_version(SpecVersion::Spec2021)
+        .call();
+
+    let extended_colors = value.get("extendedColors").and_then(Value::as_array);
+    for custom in extended_colors.into_iter().flatten() {
+        let name = custom
+            .get("name")
+            .and_then(Value::as_str)
+            .ok_or(ThemeParseError::MissingField("extendedColors.name"))?;
+        let color = custom
+            .get("color")
+            .and_then(Value::as_str)
+            .ok_or(ThemeParseError::MissingField("extendedColors.color"))?;
+        let color = parse_color(&format!("extendedColors.{name}"), color)?;
+        let blend = custom
+            .get("harmonized")
+            .and_then(Value::as_bool)
+            .unwrap_or(true);
+        theme.with_custom_color(name, color, blend);
+    }
+    Ok(theme)
+}
+
+fn parse_color(field: &str, hex: &str) -> Result<Argb, ThemeParseError> {
+    Argb::from_hex(hex).map_err(|source| ThemeParseError::InvalidColor {
+        field: field.to_owned(),
+        source,
+    })
+}
+
+fn palette_json(palette: &TonalPalette) -> Value {
+    MATERIAL_JSON_TONES
+        .iter()
+        .map(|&tone| (tone.to_string(), Value::String(palette.tone(tone).to_hex())))
+        .collect::<Map<_, _>>()
+        .into()
+}
+
+fn scheme_json(scheme: &MaterializedScheme) -> Value {
+    material_roles(scheme)
+        .into_iter()
+        .map(|(role, argb)| (role.to_owned(), Value::String(argb.to_hex())))
+        .collect::<Map<_, _>>()
+        .into()
+}
+
+/// The scheme roles as named by the Material Theme Builder.
+fn material_roles(s: &MaterializedScheme) -> [(&'static str, Argb); 49] {
+    [
+        ("primary", s.primary),
+        ("surfaceTint", s.surface_tint),
+        ("onPrimary", s.on_primary),
+        ("primaryContainer", s.primary_container),
+        ("onPrimaryContainer", s.on_primary_container),
+        ("secondary", s.secondary),
+        ("onSecondary", s.on_secondary),
+        ("secondaryContainer", s.secondary_container),
+        ("onSecondaryContainer", s.on_secondary_container),
+        ("tertiary", s.tertiary),
+        ("onTertiary", s.on_tertiary),
+        ("tertiaryContainer", s.tertiary_container),
+        ("onTertiaryContainer", s.on_tertiary_container),
+        ("error", s.error),
+        ("onError", s.on_error),
+        ("errorContainer", s.error_container),
+        ("onErrorContainer", s.on_error_container),
+        ("background", s.background),
+        ("onBackground", s.on_background),
+        ("surface", s.surface),
+        ("onSurface", s.on_surface),
+        ("surfaceVariant", s.surface_variant),
+        ("onSurfaceVariant", s.on_surface_variant),
+        ("outline", s.outline),
+        ("outlineVariant", s.outline_variant),
+        ("shadow", s.shadow),
+        ("scrim", s.scrim),
+        ("inverseSurface", s.inverse_surface),
+        ("inverseOnSurface", s.inverse_on_surface),
+        ("inversePrimary", s.inverse_primary),
+        ("primaryFixed", s.primary_fixed),
+        ("onPrimaryFixed", s.on_primary_fixed),
+        ("primaryFixedDim", s.primary_fixed_dim),
+        ("onPrimaryFixedVariant", s.on_primary_fixed_variant),
+        ("secondaryFixed", s.secondary_fixed),
+        ("onSecondaryFixed", s.on_secondary_fixed),
+        ("secondaryFixedDim", s.secondary_fixed_dim),
+        ("onSecondaryFixedVariant", s.on_secondary_fixed_variant),
+        ("tertiaryFixed", s.tertiary_fixed),
+        ("onTertiaryFixed", s.on_tertiary_fixed),
+        ("tertiaryFixedDim", s.tertiary_fixed_dim),
+        ("onTertiaryFixedVariant", s.on_tertiary_fixed_variant),
+        ("surfaceDim", s.surface_dim),
+        ("surfaceBright", s.surface_bright),
+        ("surfaceContainerLowest", s.surface_container_lowest),
+        ("surfaceContainerLow", s.surface_container_low),
+        ("surfaceContainer", s.surface_container),
+        ("surfaceContainerHigh", s.surface_container_high),
+        ("surfaceContainerHighest", s.surface_container_highest),
+    ]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_material_json_shape() {
+        // TONAL_SPOT light primary at contrast 0 in tests/assets/json/reference_schemes_large.json
+        let seed = Argb(0xFFD20B29);
+        let theme = theme_from_color(seed)
+            .variant(Variant::TonalSpot)
+            .spec_version(SpecVersion::Spec2021)
+            .call();
+        let json: Value = serde_json::from_str(&to_material_json(&theme)).expect("valid JSON");
+
+        let mut keys: Vec<_> = json.as_object().expect("object").keys().cloned().collect();
+        keys.sort();
+        assert_eq!(
+            keys,
+            [
+                "coreColors",
+                "extendedColors",
+                "palettes",
+                "schemes",
+                "seed"
+            ]
+        );
+        assert_eq!(json["seed"], "#D20B29");
+        assert_eq!(json["schemes"]["light"]["primary"], "#904A47");
+        assert_eq!(
+            json["schemes"]["light"].as_object().expect("object").len(),
+            49
+        );
+        assert_eq!(
+            json["schemes"]["dark"]["primary"],
+            theme.schemes.dark.primary.to_hex()
+        );
+        assert_eq!(json["palettes"]["neutral-variant"]["0"], "#000000");
+        assert_eq!(json["palettes"]["primary"]["100"], "#FFFFFF");
+    }
+
+    #[test]
+    fn test_material_json_extended_colors() {
+        let mut theme = theme_from_color(Argb(0xFF4285F4)).call();
+        theme.with_custom_color("brand", Argb(0xFF00FF00), true);
+        let json: Value = serde_json::from_str(&to_material_json(&theme)).expect("valid JSON");
+
+        let extended = json["extendedColors"].as_array().expect("array");
+        assert_eq!(extended.len(), 1);
+        assert_eq!(extended[0]["name"], "brand");
+        assert_eq!(extended[0]["color"], "#00FF00");
+        assert_eq!(extended[0]["harmonized"], true);
+    }
+
+    #[test]
+    fn test_material_json_round_trip() {
+        let mut theme = theme_from_color(Argb(0xFF6750A4))
+            .variant(Variant::TonalSpot)
+            .spec_version(SpecVersion::Spec2021)
+            .call();
+        theme.with_custom_color("brand", Argb(0xFF00A86B), true);
+        theme.with_custom_color("warning", Argb(0xFFFFB300), false);
+
+        let exported = to_material_json(&theme);
+        let imported = from_material_json(&exported).expect("valid theme JSON");
+        assert_eq!(imported, theme);
+        assert_eq!(to_material_json(&imported), exported);
+    }
+
+    #[test]
+    fn test_material_json_import_errors() {
+        assert!(matches!(
+            from_material_json("not json"),
+            Err(ThemeParseError::InvalidJson(_))
+        ));
+        assert!(matches!(
+            from_material_json("{}"),
+            Err(ThemeParseError::MissingField("seed"))
+        ));
+        assert!(matches!(
+            from_material_json(r##"{"seed": "#12345"}"##),
+            Err(ThemeParseError::InvalidColor { .. })
+        ));
+
+        let theme = from_material_json(r##"{"coreColors": {"primary": "#D20B29"}}"##)
+            .expect("seed from coreColors");
+        assert_eq!(theme.source_color, Argb(0xFFD20B29));
+        assert!(theme.custom_colors.is_empty());
+    }
+}
@@ -3,12 +3,17 @@ mod error;
 #[cfg(feature = "image")]
 mod image_extraction_helpers;
 #[cfg(feature = "serde")]
+mod material_json;
+#[cfg(feature = "serde")]
 mod serde_impls;
 mod structs;
 mod theme_helpers;
 
 pub use contrast_helpers::*;
+pub use error::*;
 #[cfg(feature = "image")]
 pub use image_extraction_helpers::*;
+#[cfg(feature = "serde")]
+pub use material_json::*;
 pub use structs::*;
 pub use theme_helpers::*;
@@ -12,6 +12,45 @@ pub struct MaterializedTheme {
     pub platform: Platform,
     pub spec_version: SpecVersion,
     pub schemes: MaterializedSchemeGroup,
+    /// Extra app-specific colors added with [`MaterializedTheme::with_custom_color`].
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub custom_colors: Vec<CustomColorGroup>,
+}
+
+/// A custom (brand) color with its generated light and dark roles.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CustomColorGroup {
+    pub name: String,
+    /// The custom color as it was provided, before any harmonization.
+    pub value: Argb,
+    /// Whether `color` was harmonized towards the theme's source color.
+    pub blend: bool,
+    /// The color the roles were generated from (harmonized if `blend` is set).
+    pub color: Argb,
+    pub light: CustomColorRoles,
+    pub dark: CustomColorRoles,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CustomColorRoles {
+    pub color: Argb,
+    pub on_color: Argb,
+    pub color_container: Argb,
+    pub on_color_container: Argb,
+}
+
+/// Achieved contrast of one foreground/background role pair, as returned by [`audit`](crate::audit).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContrastReport {
+    pub foreground: String,
+    pub background: String,
+    /// WCAG contrast ratio between the resolved colors.
+    pub ratio: f64,
+    /// Whether `ratio` meets WCAG AA for normal text (4.5:1).
+    pub passes_aa: bool,
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -1,13 +1,18 @@
+use crate::blend::blend_functions::Blend;
 use crate::dynamic::color_spec::{Platform, SpecVersion};
 use crate::dynamic::dynamic_scheme::DynamicScheme;
 use crate::dynamic::material_dynamic_colors::MaterialDynamicColors;
 use crate::dynamic::variant::Variant;
-use crate::helpers::{MaterializedScheme, MaterializedSchemeGroup, MaterializedTheme};
-use crate::scheme::{
-    SchemeCmf, SchemeContent, SchemeExpressive, SchemeFidelity, SchemeFruitSalad, SchemeMonochrome,
-    SchemeNeutral, SchemeRainbow, SchemeTonalSpot, SchemeVibrant,
+use crate::hct::Hct;
+use crate::helpers::{
+    CustomColorGroup, CustomColorRoles, MaterializedScheme, MaterializedSchemeGroup,
+    MaterializedTheme,
 };
+use crate::palettes::tonal_palette::TonalPalette;
+use crate::quantize::{Quantizer, QuantizerCelebi};
+use crate::score::score_colors::Score;
 use crate::utils::color_utils::Argb;
+use std::collections::HashMap;
 
 /// Generates a materialized theme from a source color.
 #[bon::builder]
@@ -69,6 +74,126 @@ pub fn theme_from_color(
         platform,
         spec_version,
         schemes: MaterializedSchemeGroup { light, dark },
+        custom_colors: Vec::new(),
+    }
+}
+
+/// Generates a materialized theme from raw image pixels: the pixels are quantized, the most
+/// suitable color is picked as the source color, and the theme is built from it.
+///
+/// This is `theme_from_image` without the `image` dependency. When no pixel is colorful enough,
+/// the theme falls back to Google Blue like [`Score::score`].
+#[bon::builder]
+pub fn theme_from_pixels(
+    /// The image pixels.
+    #[builder(start_fn)]
+    pixels: &[Argb],
+    /// Max colors to pass to the quantizer.
+    #[builder(default = 128)]
+    quantize_max_colors: usize,
+    /// Which variant to use
+    #[builder(default = Variant::Vibrant)]
+    variant: Variant,
+    /// Contrast level.
+    ///  - `0.0` for default contrast.
+    ///  - `0.5` for medium contrast.
+    ///  - `1.0` for highest contrast.
+    ///  - `-1.0` for reduced contrast.
+    #[builder(default = 0.0)]
+    contrast_level: f64,
+    /// `SpecVersion` tracks which version of the Material Design dynamic color spec the algorithms are following.
+    #[builder(default = SpecVersion::Spec2026)]
+    spec_version: SpecVersion,
+    /// What platform to optimize colors for.
+    #[builder(default = Platform::Phone)]
+    platform: Platform,
+) -> MaterializedTheme {
+    let source_color = seed_candidates(pixels, quantize_max_colors, 1)[0];
+
+    theme_from_color(source_color)
+        .spec_version(spec_version)
+        .platform(platform)
+        .contrast_level(contrast_level)
+        .variant(variant)
+        .call()
+}
+
+/// Picks up to `count` source color candidates from raw image pixels, most suitable first, e.g.
+/// for a "pick your accent" UI. Candidates have hues spread as far apart as possible.
+///
+/// When no pixel is colorful enough, the only candidate is Google Blue, like [`Score::score`].
+#[must_use]
+pub fn seed_candidates_from_pixels(pixels: &[Argb], count: usize) -> Vec<Argb> {
+    seed_candidates(pixels, 128, count)
+}
+
+/// Quantizes `pixels` to at most `quantize_max_colors` colors and scores them.
+pub(super) fn seed_candidates(
+    pixels: &[Argb],
+    quantize_max_colors: usize,
+    count: usize,
+) -> Vec<Argb> {
+    let result = QuantizerCelebi::new().quantize(pixels, quantize_max_colors);
+    Score::score(&result.color_to_count)
+        .desired_count(count)
+        .call()
+}
+
+/// Generates a materialized theme for every [`Platform`], e.g. to preview phone and watch side by
+/// side. Uses the latest spec version; see [`theme_from_color`] for the other parameters.
+#[must_use]
+pub fn themes_for_all_platforms(
+    source_color: Argb,
+    variant: Variant,
+    contrast_level: f64,
+) -> HashMap<Platform, MaterializedTheme> {
+    Platform::all()
+        .iter()
+        .map(|&platform| {
+            let theme = theme_from_color(source_color)
+                .variant(variant)
+                .contrast_level(contrast_level)
+                .platform(platform)
+                .call();
+            (platform, theme)
+        })
+        .collect()
+}
+
+impl MaterializedTheme {
+    /// Adds a custom color (e.g. a brand accent) to the theme.
+    ///
+    /// When `blend` is true the color is first harmonized towards the theme's source color
+    /// with [`Blend::harmonize`]. The roles are taken from a palette with the color's hue and
+    /// at least 48 chroma, using tones 40/100/90/10 for light and 80/20/30/90 for dark.
+    pub fn with_custom_color(&mut self, name: &str, argb: Argb, blend: bool) -> &mut Self {
+        let color = if blend {
+            Blend::harmonize(argb, self.source_color)
+        } else {
+            argb
+        };
+        let hct = Hct::from_argb(color);
+        let palette = TonalPalette::from_hue_and_chroma(hct.hue(), hct.chroma().max(48.0));
+
+        self.custom_colors.push(CustomColorGroup {
+            name: name.to_owned(),
+            value: argb,
+            blend,
+            color,
+            light: CustomColorRoles {
+                color: palette.tone(40),
+                on_color: palette.tone(100),
+                color_container: palette.tone(90),
+                on_color_container: palette.tone(10),
+            },
+            dark: CustomColorRoles {
+                color: palette.tone(80),
+                on_color: palette.tone(20),
+                color_container: palette.tone(30),
+                on_color_container: palette.tone(90),
+            },
+        });
+        self
     }
 }
 
@@ -81,48 +206,14 @@ fn create_dynamic_scheme(
     spec_version: SpecVersion,
     platform: Platform,
 ) -> DynamicScheme {
-    match variant {
-        Variant::TonalSpot => SchemeTonalSpot::builder(source_color, is_dark, contrast_level)
-            .spec_version(spec_version)
-            .platform(platform)
-            .build(),
-        Variant::Vibrant => SchemeVibrant::builder(source_color, is_dark, contrast_level)
-            .spec_version(spec_version)
-            .platform(platform)
-            .build(),
-        Variant::Expressive => SchemeExpressive::builder(source_color, is_dark, contrast_level)
-            .spec_version(spec_version)
-            .platform(platform)
-            .build(),
-        Variant::Content => SchemeContent::builder(source_color, is_dark, contrast_level)
-            .spec_version(spec_version)
-            .platform(platform)
-            .build(),
-        Variant::Fidelity => SchemeFidelity::builder(source_color, is_dark, contrast_level)
-            .spec_version(spec_version)
-            .platform(platform)
-            .build(),
-        Variant::Monochrome => SchemeMonochrome::builder(source_color, is_dark, contrast_level)
-            .spec_version(spec_version)
-            .platform(platform)
-            .build(),
-        Variant::Neutral => SchemeNeutral::builder(source_color, is_dark, contrast_level)
-            .spec_version(spec_version)
-            .platform(platform)
-            .build(),
-        Variant::Rainbow => SchemeRainbow::builder(source_color, is_dark, contrast_level)
-            .spec_version(spec_version)
-            .platform(platform)
-            .build(),
-        Variant::FruitSalad => SchemeFruitSalad::builder(source_color, is_dark, contrast_level)
-            .spec_version(spec_version)
-            .platform(platform)
-            .build(),
-        Variant::Cmf => SchemeCmf::builder(source_color, is_dark, contrast_level)
-            .spec_version(spec_version)
-            .platform(platform)
-            .build(),
-    }
+    DynamicScheme::from_source_colors(
+        &[Hct::from_argb(source_color)],
+        variant,
+        is_dark,
+        contrast_level,
+        platform,
+        spec_version,
+    )
 }
 
 /// Extracts all ARGB values from a `DynamicScheme` into a `MaterializedScheme`.
@@ -438,8 +529,8 @@ fn materialize(scheme: &DynamicScheme, mdc: &MaterialDynamicColors) -> Materiali
 mod tests {
     use super::*;
     use crate::dynamic::variant::Variant;
-    use crate::hct::Hct;
     use crate::utils::color_utils::Argb;
+    use crate::utils::math_utils::MathUtils;
 
     const GOOGLE_BLUE: Argb = Argb(0xFF4285F4);
 
@@ -537,4 +628,114 @@ mod tests {
             theme_2026.schemes.light.surface_container
         );
     }
+
+    #[test]
+    fn test_custom_color_blend_shifts_hue_towards_source() {
+        let red = Argb(0xFFFF0000);
+        let mut theme = theme_from_color(GOOGLE_BLUE).call();
+        theme.with_custom_color("brand", red, true);
+
+        let group = &theme.custom_colors[0];
+        assert_eq!(group.name, "brand");
+        assert_eq!(group.value, red);
+        assert_ne!(group.color, red);
+
+        let source_hue = Hct::from_argb(GOOGLE_BLUE).hue();
+        let before = MathUtils::difference_degrees(Hct::from_argb(red).hue(), source_hue);
+        let after = MathUtils::difference_degrees(Hct::from_argb(group.color).hue(), source_hue);
+        assert!(after < before);
+
+        // Light and dark roles follow the usual tone layout
+        assert!(Hct::from_argb(group.light.color).tone() < Hct::from_argb(group.dark.color).tone());
+        assert_eq!(group.light.on_color, Argb(0xFFFFFFFF));
+    }
+
+    #[test]
+    fn test_custom_color_without_blend_is_untouched() {
+        let red = Argb(0xFFFF0000);
+        let mut theme = theme_from_color(GOOGLE_BLUE).call();
+        theme
+            .with_custom_color("brand", red, false)
+            .with_custom_color("other", GOOGLE_BLUE, false);
+
+        assert_eq!(theme.custom_colors.len(), 2);
+        assert_eq!(theme.custom_colors[0].color, red);
+        assert!(!theme.custom_colors[0].blend);
+    }
+
+    #[test]
+    fn test_themes_for_all_platforms() {
+        let themes = themes_for_all_platforms(GOOGLE_BLUE, Variant::Neutral, 0.0);
+        assert_eq!(themes.len(), Platform::all().len());
+
+        let phone = &themes[&Platform::Phone];
+        let watch = &themes[&Platform::Watch];
+        assert_eq!(phone.platform, Platform::Phone);
+        assert_eq!(watch.platform, Platform::Watch);
+        assert_eq!(
+            *phone,
+            theme_from_color(GOOGLE_BLUE)
+                .variant(Variant::Neutral)
+                .call()
+        );
+
+        let phone_tone = Hct::from_argb(phone.schemes.dark.primary).tone();
+        let watch_tone = Hct::from_argb(watch.schemes.dark.primary).tone();
+        assert!(
+            (phone_tone - watch_tone).abs() > 1.0,
+            "{phone_tone} vs {watch_tone}"
+        );
+    }
+
+    #[test]
+    fn test_theme_from_pixels() {
+        // 90% shades of blue with a little orange.
+        let mut pixels: Vec<Argb> = (0..30u8)
+            .flat_map(|g| (0..30u8).map(move |b| Argb::from_rgb(30, 60 + g, 200 + b)))
+            .collect();
+        pixels.extend([Argb(0xFFFF8800); 100]);
+
+        let theme = theme_from_pixels(&pixels)
+            .variant(Variant::TonalSpot)
+            .call();
+        let blue_hue = Hct::from_argb(Argb::from_rgb(30, 70, 220)).hue();
+        let source_hue = Hct::from_argb(theme.source_color).hue();
+        let primary_hue = Hct::from_argb(theme.schemes.light.primary).hue();
+        assert!(
+            MathUtils::difference_degrees(source_hue, blue_hue) < 10.0,
+            "{source_hue}"
+        );
+        assert!(
+            MathUtils::difference_degrees(primary_hue, blue_hue) < 15.0,
+            "{primary_hue}"
+        );
+        assert_eq!(theme.variant, Variant::TonalSpot);
+
+        let grey = theme_from_pixels(&[Argb(0xFF808080); 16]).call();
+        assert_eq!(grey.source_color, Argb(0xff4285f4));
+    }
+
+    #[test]
+    fn test_seed_candidates_from_pixels() {
+        let red = Argb(0xFFD32F2F);
+        let blue = Argb(0xFF1976D2);
+        let mut pixels = vec![red; 600];
+        pixels.extend(vec![blue; 400]);
+
+        let candidates = seed_candidates_from_pixels(&pixels, 4);
+        assert_eq!(candidates.len(), 2, "{candidates:?}");
+        let hues: Vec<f64> = candidates
+            .iter()
+            .map(|&c| Hct::from_argb(c).hue())
+            .collect();
+        for expected in [red, blue] {
+            let hue = Hct::from_argb(expected).hue();
+            assert!(
+                hues.iter()
+                    .any(|&h| MathUtils::difference_degrees(h, hue) < 5.0),
+                "{hues:?} misses {hue}"
+            );
+        }
+        assert_eq!(seed_candidates_from_pixels(&pixels, 1).len(), 1);
+    }
 }
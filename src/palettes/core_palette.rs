@@ -0,0 +1,88 @@
+use crate::hct::hct_color::Hct;
+use crate::palettes::tonal_palette::TonalPalette;
+use crate::utils::color_utils::Argb;
+use crate::utils::math_utils::MathUtils;
+
+/// The six key palettes of a color scheme, generated from a single source color.
+///
+/// Mirrors `CorePalette` from the upstream libraries. Newer code should prefer building a
+/// [`DynamicScheme`](crate::dynamic::dynamic_scheme::DynamicScheme), which derives its palettes
+/// from the chosen variant and spec version.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CorePalette {
+    pub primary: TonalPalette,
+    pub secondary: TonalPalette,
+    pub tertiary: TonalPalette,
+    pub neutral: TonalPalette,
+    pub neutral_variant: TonalPalette,
+    pub error: TonalPalette,
+}
+
+impl CorePalette {
+    /// Creates key palettes from a source color.
+    #[must_use]
+    pub fn of(argb: Argb) -> Self {
+        Self::new(argb, false)
+    }
+
+    /// Creates content key palettes from a source color, which stay closer to the source color's
+    /// chroma.
+    #[must_use]
+    pub fn content_of(argb: Argb) -> Self {
+        Self::new(argb, true)
+    }
+
+    fn new(argb: Argb, is_content: bool) -> Self {
+        let hct = Hct::from_argb(argb);
+        let hue = hct.hue();
+        let chroma = hct.chroma();
+        let tertiary_hue = MathUtils::sanitize_degrees_double(hue + 60.0);
+
+        if is_content {
+            Self {
+                primary: TonalPalette::from_hue_and_chroma(hue, chroma),
+                secondary: TonalPalette::from_hue_and_chroma(hue, chroma / 3.0),
+                tertiary: TonalPalette::from_hue_and_chroma(tertiary_hue, chroma / 2.0),
+                neutral: TonalPalette::from_hue_and_chroma(hue, (chroma / 12.0).min(4.0)),
+                neutral_variant: TonalPalette::from_hue_and_chroma(hue, (chroma / 6.0).min(8.0)),
+                error: TonalPalette::from_hue_and_chroma(25.0, 84.0),
+            }
+        } else {
+            Self {
+                primary: TonalPalette::from_hue_and_chroma(hue, chroma.max(48.0)),
+                secondary: TonalPalette::from_hue_and_chroma(hue, 16.0),
+                tertiary: TonalPalette::from_hue_and_chroma(tertiary_hue, 24.0),
+                neutral: TonalPalette::from_hue_and_chroma(hue, 4.0),
+                neutral_variant: TonalPalette::from_hue_and_chroma(hue, 8.0),
+                error: TonalPalette::from_hue_and_chroma(25.0, 84.0),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_core_palette_of() {
+        let palette = CorePalette::of(Argb(0xff4285f4));
+        let source_hue = Hct::from_argb(Argb(0xff4285f4)).hue();
+
+        assert!((palette.primary.hue - source_hue).abs() < 1e-9);
+        assert!(palette.primary.chroma >= 48.0);
+        assert!(palette.neutral.chroma <= 4.0);
+        assert!(palette.neutral.key_color.chroma() < 5.0);
+        assert!((palette.error.hue - 25.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_core_palette_content_of() {
+        let source = Hct::from_argb(Argb(0xff4285f4));
+        let palette = CorePalette::content_of(Argb(0xff4285f4));
+
+        assert!((palette.primary.chroma - source.chroma()).abs() < 1e-9);
+        assert!((palette.secondary.chroma - source.chroma() / 3.0).abs() < 1e-9);
+        assert_ne!(palette, CorePalette::of(Argb(0xff4285f4)));
+    }
+}
@@ -1,2 +1,4 @@
+pub mod core_palette;
 pub mod core_palettes;
+pub mod scheme_colors;
 pub mod tonal_palette;
@@ -0,0 +1,76 @@
+//! Classic color-wheel schemes built on [`TemperatureCache`].
+
+use crate::hct::hct_color::Hct;
+use crate::temperature::temperature_cache::TemperatureCache;
+use crate::utils::math_utils::MathUtils;
+
+/// `count` colors of neighbouring hues, equidistant in temperature, starting with `seed`.
+///
+/// Uses a color wheel of 12 divisions, see
+/// [`TemperatureCache::get_analogous_colors_with_options`].
+#[must_use]
+pub fn analogous(seed: Hct, count: usize) -> Vec<Hct> {
+    if count == 0 {
+        return Vec::new();
+    }
+    TemperatureCache::new(seed).get_analogous_colors_with_options(count, 12)
+}
+
+/// The seed and the two colors 120° away from it in hue, with the seed's chroma and tone.
+#[must_use]
+pub fn triadic(seed: Hct) -> [Hct; 3] {
+    let rotate = |degrees: f64| {
+        Hct::new(
+            MathUtils::sanitize_degrees_double(seed.hue() + degrees),
+            seed.chroma(),
+            seed.tone(),
+        )
+    };
+    [seed, rotate(120.0), rotate(240.0)]
+}
+
+/// The color opposite `seed` in temperature, see [`TemperatureCache::complement`].
+#[must_use]
+pub fn complementary(seed: Hct) -> Hct {
+    TemperatureCache::new(seed).complement()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hct::HueFamily;
+    use crate::utils::color_utils::Argb;
+
+    #[test]
+    fn test_complementary_of_red_is_cool() {
+        // Upstream's temperature cache tests pin red's complement to 0xFF007BFC (hue ~265) and
+        // blue's to 0xFF9D0002 (hue ~27).
+        let red = Hct::from_argb(Argb(0xffff0000));
+        let complement = complementary(red);
+        assert_eq!(red.hue_family(), HueFamily::Red);
+        assert_eq!(complement.hue_family(), HueFamily::Blue);
+        assert!(MathUtils::difference_degrees(complement.hue(), red.hue()) > 120.0);
+
+        let blue = Hct::from_argb(Argb(0xff0000ff));
+        assert_eq!(blue.hue_family(), HueFamily::Purple);
+        assert_eq!(complementary(blue).hue_family(), HueFamily::Red);
+    }
+
+    #[test]
+    fn test_triadic_hues() {
+        let seed = Hct::new(30.0, 30.0, 60.0);
+        let [first, second, third] = triadic(seed);
+        assert_eq!(first, seed);
+        for (a, b) in [(first, second), (second, third), (third, first)] {
+            assert!((MathUtils::difference_degrees(a.hue(), b.hue()) - 120.0).abs() < 3.0);
+        }
+    }
+
+    #[test]
+    fn test_analogous() {
+        let seed = Hct::from_argb(Argb(0xff4285f4));
+        let colors = analogous(seed, 5);
+        assert_eq!(colors.len(), 5);
+        assert!(analogous(seed, 0).is_empty());
+    }
+}
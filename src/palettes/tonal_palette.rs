@@ -1,5 +1,7 @@
+use crate::hct::cam16::Cam16;
 use crate::hct::hct_color::Hct;
 use crate::utils::color_utils::Argb;
+use crate::utils::math_utils::MathUtils;
 use std::sync::Arc;
 use std::sync::atomic::{AtomicU32, Ordering};
 
@@ -42,6 +44,11 @@ impl std::hash::Hash for TonalPalette {
 }
 
 impl TonalPalette {
+    /// The tone stops shown for a palette in the Material tooling.
+    const STANDARD_TONES: [f64; 13] = [
+        0.0, 10.0, 20.0, 30.0, 40.0, 50.0, 60.0, 70.0, 80.0, 90.0, 95.0, 99.0, 100.0,
+    ];
+
     fn new(hue: f64, chroma: f64, key_color: Hct) -> Self {
         Self {
             hue,
@@ -95,6 +102,40 @@ impl TonalPalette {
         Self::new(hue, chroma, key_color)
     }
 
+    /// The hue of the palette, in HCT. Ranges from 0 to 360.
+    #[must_use]
+    pub const fn hue(&self) -> f64 {
+        self.hue
+    }
+
+    /// The chroma the palette was created with, in HCT. Individual tones can have less chroma
+    /// when the requested chroma doesn't fit in sRGB at that tone.
+    #[must_use]
+    pub const fn chroma(&self) -> f64 {
+        self.chroma
+    }
+
+    /// The palette's key color: the first tone, starting from T50, that matches the palette's
+    /// chroma.
+    #[must_use]
+    pub const fn key_color(&self) -> Hct {
+        self.key_color
+    }
+
+    /// Interpolates between two palettes, e.g. to animate a theme change. Hue travels along the
+    /// shortest arc and chroma linearly; the key color is recomputed for the result. `t` is
+    /// clamped to `0.0..=1.0`.
+    #[must_use]
+    pub fn lerp(a: &Self, b: &Self, t: f64) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let hue_delta = MathUtils::difference_degrees(a.hue, b.hue)
+            * MathUtils::rotation_direction(a.hue, b.hue);
+        Self::from_hue_and_chroma(
+            MathUtils::sanitize_degrees_double(hue_delta.mul_add(t, a.hue)),
+            MathUtils::lerp(a.chroma, b.chroma, t),
+        )
+    }
+
     /// Create an ARGB color with HCT hue and chroma of this Tones instance, and the provided HCT tone.
     ///
     /// # Arguments
@@ -126,12 +167,71 @@ impl TonalPalette {
         color
     }
 
+    /// The Material tone stops: 0, 10, 20, 30, 40, 50, 60, 70, 80, 90, 95, 99 and 100.
+    #[must_use]
+    pub const fn standard_tones() -> [f64; 13] {
+        Self::STANDARD_TONES
+    }
+
+    /// Iterates over the [standard tones](Self::standard_tones) of this palette, yielding each
+    /// tone together with its ARGB color.
+    pub fn tones(&self) -> impl Iterator<Item = (f64, Argb)> + '_ {
+        Self::STANDARD_TONES
+            .into_iter()
+            .map(|tone| (tone, self.tone(tone as i32)))
+    }
+
+    /// Returns the color of each of `tones`, in order.
+    ///
+    /// Whole tones go through [`TonalPalette::tone`] and its cache; fractional tones are solved
+    /// directly. `palette.ramp(&TonalPalette::standard_tones())` gives a full swatch strip.
+    #[must_use]
+    #[allow(clippy::float_cmp)]
+    pub fn ramp(&self, tones: &[f64]) -> Vec<Argb> {
+        tones
+            .iter()
+            .map(|&tone| {
+                if tone.fract() == 0.0 {
+                    self.tone(tone as i32)
+                } else {
+                    self.get_hct(tone).to_argb()
+                }
+            })
+            .collect()
+    }
+
     /// Given a tone, use hue and chroma of palette to create a color, and return it as HCT.
     #[must_use]
     pub fn get_hct(&self, tone: f64) -> Hct {
         Hct::new(self.hue, self.chroma, tone)
     }
 
+    /// Finds a color in this palette's hue with as much chroma as possible, up to
+    /// `target_chroma`, using the same search as [`TonalPalette::key_color`].
+    ///
+    /// The tone closest to T50 that reaches `target_chroma` is used; if no tone reaches it, the
+    /// tone with the most chroma is used instead.
+    #[must_use]
+    pub fn key_color_at_chroma(&self, target_chroma: f64) -> Hct {
+        KeyColor::new(self.hue, target_chroma).create()
+    }
+
+    /// Returns the whole tone in 0..=100 whose generated color is perceptually closest to `argb`.
+    ///
+    /// Every tone of the palette is compared to `argb` by CAM16-UCS distance, so hue and chroma
+    /// count as well as lightness: a color off the palette's hue lands on the tone that looks most
+    /// like it, not simply on its own L*. Useful for mapping an existing color onto a generated
+    /// ramp.
+    #[must_use]
+    pub fn nearest_tone(&self, argb: Argb) -> f64 {
+        let target = Cam16::from_argb(argb);
+        let (nearest, _) = (0..=100)
+            .map(|tone| (tone, target.distance(&Cam16::from_argb(self.tone(tone)))))
+            .min_by(|(_, a), (_, b)| a.total_cmp(b))
+            .expect("the tone range is not empty");
+        f64::from(nearest)
+    }
+
     fn average_argb(argb1: Argb, argb2: Argb) -> Argb {
         let red1 = f32::from(argb1.red());
         let green1 = f32::from(argb1.green());
@@ -268,6 +368,71 @@ mod tests {
         assert!((palette.key_color.chroma() - chroma).abs() < 1.0);
     }
 
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_getters() {
+        let palette = TonalPalette::from_hue_and_chroma(120.0, 40.0);
+        assert_eq!(palette.hue(), 120.0);
+        assert_eq!(palette.chroma(), 40.0);
+        assert_eq!(palette.key_color(), palette.key_color);
+        assert!((palette.key_color().hue() - 120.0).abs() < 1.0);
+        assert!((palette.key_color().chroma() - 40.0).abs() < 1.0);
+
+        // Out-of-gamut chroma is kept as requested; the key color holds what is achievable.
+        let vivid = TonalPalette::from_hue_and_chroma(120.0, 200.0);
+        assert_eq!(vivid.chroma(), 200.0);
+        assert!(vivid.key_color().chroma() < 200.0);
+    }
+
+    #[test]
+    fn test_ramp() {
+        let palette = TonalPalette::from_argb(Argb(0xFF4285F4));
+        let ends = palette.ramp(&[0.0, 100.0]);
+        assert_eq!(ends, vec![Argb(0xFF000000), Argb(0xFFFFFFFF)]);
+
+        let standard = palette.ramp(&TonalPalette::standard_tones());
+        let expected: Vec<Argb> = palette.tones().map(|(_, argb)| argb).collect();
+        assert_eq!(standard, expected);
+
+        let half = palette.ramp(&[42.5])[0];
+        assert!((half.lstar() - 42.5).abs() < 0.5);
+    }
+
+    #[test]
+    fn test_key_color_at_chroma() {
+        let palette = TonalPalette::from_hue_and_chroma(140.0, 80.0);
+
+        let anchor = palette.key_color_at_chroma(24.0);
+        assert!(anchor.chroma() <= 24.0 + 0.5, "{}", anchor.chroma());
+        assert!(anchor.chroma() >= 24.0 - 0.5, "{}", anchor.chroma());
+        assert!((anchor.tone() - 50.0).abs() < 1.0);
+
+        // Unreachable chroma settles on the most colorful tone of the hue.
+        let peak = palette.key_color_at_chroma(200.0);
+        let max_chroma = (0..=100)
+            .map(|tone| Hct::new(140.0, 200.0, f64::from(tone)).chroma())
+            .fold(0.0, f64::max);
+        assert!(peak.chroma() <= max_chroma + 0.5);
+        assert!(
+            peak.chroma() >= max_chroma - 1.0,
+            "{} < {max_chroma}",
+            peak.chroma()
+        );
+    }
+
+    #[test]
+    fn test_standard_tones() {
+        let palette = TonalPalette::from_hue_and_chroma(250.0, 36.0);
+        let tones: Vec<(f64, Argb)> = palette.tones().collect();
+
+        assert_eq!(tones.len(), TonalPalette::standard_tones().len());
+        assert_eq!(tones.first(), Some(&(0.0, Argb(0xFF000000))));
+        assert_eq!(tones.last(), Some(&(100.0, Argb(0xFFFFFFFF))));
+        for (tone, argb) in tones {
+            assert_eq!(argb, palette.tone(tone as i32));
+        }
+    }
+
     #[test]
     fn test_out_of_bounds_tone() {
         let palette = TonalPalette::from_hue_and_chroma(120.0, 40.0);
@@ -278,4 +443,40 @@ mod tests {
         let color_neg = palette.tone(-10);
         assert_ne!(color_neg.0, 0);
     }
+
+    #[test]
+    #[allow(clippy::float_cmp)]
+    fn test_nearest_tone() {
+        let palette = TonalPalette::from_argb(Argb(0xFF4285F4));
+        for tone in [10.0, 40.0, 80.0] {
+            let argb = palette.get_hct(tone).to_argb();
+            assert_eq!(palette.nearest_tone(argb), tone);
+        }
+        assert_eq!(palette.nearest_tone(Argb(0xFFFFFFFF)), 100.0);
+        assert_eq!(palette.nearest_tone(Argb(0xFF000000)), 0.0);
+
+        // Off-palette colors still land on the tone that looks closest.
+        let gray = Argb::from_lstar(60.0);
+        let nearest = palette.nearest_tone(gray);
+        let distance = |tone: f64| {
+            Cam16::from_argb(gray).distance(&Cam16::from_argb(palette.tone(tone as i32)))
+        };
+        assert!(distance(nearest) <= distance(60.0));
+    }
+
+    #[test]
+    fn test_lerp() {
+        let palette = TonalPalette::from_hue_and_chroma(200.0, 36.0);
+        assert_eq!(TonalPalette::lerp(&palette, &palette, 0.5), palette);
+
+        let a = TonalPalette::from_hue_and_chroma(350.0, 20.0);
+        let b = TonalPalette::from_hue_and_chroma(10.0, 40.0);
+        let mid = TonalPalette::lerp(&a, &b, 0.5);
+        assert!(MathUtils::difference_degrees(mid.hue, 0.0) < 1e-9);
+        assert!((mid.chroma - 30.0).abs() < 1e-9);
+        assert_eq!(
+            TonalPalette::lerp(&a, &b, 5.0),
+            TonalPalette::lerp(&a, &b, 1.0)
+        );
+    }
 }
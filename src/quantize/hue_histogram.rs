@@ -0,0 +1,109 @@
+use crate::hct::Hct;
+use crate::utils::color_utils::Argb;
+use crate::utils::math_utils::MathUtils;
+
+/// Distribution of the HCT hues in `pixels`, weighted by chroma.
+///
+/// Bucket `i` covers hues from `i * 360 / buckets` up to the next bucket. Every pixel adds its
+/// chroma to the bucket of its hue, so greys barely count and vivid colors dominate. The buckets
+/// are normalized to sum to 1, or are all zero when no pixel has any chroma. Returns an empty
+/// histogram when `buckets` is 0.
+#[must_use]
+pub fn hue_histogram(pixels: &[Argb], buckets: usize) -> Vec<f64> {
+    let mut histogram = vec![0.0; buckets];
+    if buckets == 0 {
+        return histogram;
+    }
+    let bucket_width = 360.0 / buckets as f64;
+    for hct in Hct::from_argb_slice(pixels) {
+        let bucket = ((hct.hue() / bucket_width) as usize).min(buckets - 1);
+        histogram[bucket] += hct.chroma();
+    }
+    let total: f64 = histogram.iter().sum();
+    if total > 0.0 {
+        for weight in &mut histogram {
+            *weight /= total;
+        }
+    }
+    histogram
+}
+
+/// The most prominent hue in `pixels`, in degrees, or `None` if no pixel has any chroma.
+///
+/// Hues are weighted by chroma as in [`hue_histogram`], and hues within about 15° of each other
+/// count together, like the hue proportions used by
+/// [`Score`](crate::score::score_colors::Score). The result is the weighted mean hue of the
+/// strongest such neighborhood.
+#[must_use]
+pub fn dominant_hue(pixels: &[Argb]) -> Option<f64> {
+    let histogram = hue_histogram(pixels, 360);
+    let neighborhood = |hue: i32| (hue - 14..=hue + 15).map(MathUtils::sanitize_degrees_int);
+    let excited = |hue: i32| -> f64 { neighborhood(hue).map(|i| histogram[i as usize]).sum() };
+
+    let peak = (0..360).max_by(|&a, &b| excited(a).total_cmp(&excited(b)))?;
+    let total = excited(peak);
+    if total <= 0.0 {
+        return None;
+    }
+    let offset: f64 = (-14..=15)
+        .zip(neighborhood(peak))
+        .map(|(offset, i)| f64::from(offset) * histogram[i as usize])
+        .sum::<f64>()
+        / total;
+    Some(MathUtils::sanitize_degrees_double(
+        f64::from(peak) + 0.5 + offset,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    #![allow(clippy::float_cmp)]
+    use super::*;
+
+    #[test]
+    fn test_hue_histogram_red_and_blue() {
+        let red = Argb(0xFFFF0000);
+        let blue = Argb(0xFF0000FF);
+        let mut pixels = vec![red; 50];
+        pixels.extend(vec![blue; 50]);
+        pixels.extend(vec![Argb(0xFF808080); 100]);
+
+        let histogram = hue_histogram(&pixels, 36);
+        assert_eq!(histogram.len(), 36);
+        assert!((histogram.iter().sum::<f64>() - 1.0).abs() < 1e-9);
+
+        let peaks: Vec<usize> = (0..36)
+            .filter(|&i| {
+                let previous = histogram[(i + 35) % 36];
+                let next = histogram[(i + 1) % 36];
+                histogram[i] > 0.1 && histogram[i] >= previous && histogram[i] >= next
+            })
+            .collect();
+        let bucket = |argb| (Hct::from_argb(argb).hue() / 10.0) as usize;
+        assert_eq!(peaks, [bucket(red), bucket(blue)]);
+
+        assert!(hue_histogram(&pixels, 0).is_empty());
+        assert!(
+            hue_histogram(&[Argb(0xFF000000)], 12)
+                .iter()
+                .all(|&w| w == 0.0)
+        );
+    }
+
+    #[test]
+    fn test_dominant_hue() {
+        let red = Argb(0xFFFF0000);
+        let mut pixels = vec![red; 80];
+        pixels.extend(vec![Argb(0xFF0000FF); 20]);
+
+        let hue = dominant_hue(&pixels).expect("colorful pixels");
+        let red_hue = Hct::from_argb(red).hue();
+        assert!(
+            MathUtils::difference_degrees(hue, red_hue) < 1.0,
+            "{hue} vs {red_hue}"
+        );
+
+        assert_eq!(dominant_hue(&[]), None);
+        assert_eq!(dominant_hue(&[Argb(0xFF000000)]), None);
+    }
+}
@@ -1,13 +1,19 @@
+pub mod hue_histogram;
 pub mod point_provider;
 pub mod point_provider_lab;
+pub mod quantize_bytes;
 pub mod quantizer;
 pub mod quantizer_celebi;
 pub mod quantizer_map;
+pub mod quantizer_streaming;
 pub mod quantizer_wsmeans;
 pub mod quantizer_wu;
 
-pub use quantizer::{Quantizer, QuantizerResult};
-pub use quantizer_celebi::QuantizerCelebi;
+pub use hue_histogram::{dominant_hue, hue_histogram};
+pub use quantize_bytes::{quantize_rgba8, quantize_rgba16};
+pub use quantizer::{QuantizeConfig, Quantizer, QuantizerResult};
+pub use quantizer_celebi::{QuantizerCelebi, quantize_seeded, quantize_with_config};
 pub use quantizer_map::QuantizerMap;
+pub use quantizer_streaming::StreamingQuantizer;
 pub use quantizer_wsmeans::QuantizerWsmeans;
 pub use quantizer_wu::QuantizerWu;
@@ -0,0 +1,75 @@
+use crate::quantize::quantizer::{Quantizer, QuantizerResult};
+use crate::quantize::quantizer_celebi::QuantizerCelebi;
+use crate::utils::color_utils::Argb;
+
+/// Quantizes a buffer of tightly packed RGBA8 pixels, as produced by e.g. `image::RgbaImage`.
+///
+/// Fully transparent pixels are skipped, the alpha channel of all other pixels is dropped. Trailing
+/// bytes that don't form a whole pixel are ignored.
+///
+/// # Arguments
+/// * `bytes` - Pixel data in `[r, g, b, a, r, g, b, a, ...]` order.
+/// * `max_colors` - The number of colors to divide the image into. A lower number of colors may be
+///   returned.
+#[must_use]
+pub fn quantize_rgba8(bytes: &[u8], max_colors: usize) -> QuantizerResult {
+    let pixels: Vec<Argb> = bytes
+        .chunks_exact(4)
+        .filter(|p| p[3] != 0)
+        .map(|p| Argb::from_rgb(p[0], p[1], p[2]))
+        .collect();
+    QuantizerCelebi::new().quantize(&pixels, max_colors)
+}
+
+/// Quantizes 16-bit-per-channel RGBA pixels, as delivered by HDR and raw pipelines.
+///
+/// Each channel is rounded to the nearest 8-bit value before quantization, since colors are
+/// represented as [`Argb`] throughout the crate. The 8-bit grid is much finer than the clusters the
+/// quantizer produces, so this does not change which colors come out in practice. Channels are
+/// taken as already encoded sRGB; linear or scene-referred data should be tone-mapped and encoded
+/// by the caller first.
+///
+/// As with [`quantize_rgba8`], fully transparent pixels are skipped and alpha is otherwise
+/// dropped.
+#[must_use]
+pub fn quantize_rgba16(pixels: &[[u16; 4]], max_colors: usize) -> QuantizerResult {
+    let to_u8 = |channel: u16| ((u32::from(channel) * 255 + 32_767) / 65_535) as u8;
+    let pixels: Vec<Argb> = pixels
+        .iter()
+        .filter(|p| p[3] != 0)
+        .map(|p| Argb::from_rgb(to_u8(p[0]), to_u8(p[1]), to_u8(p[2])))
+        .collect();
+    QuantizerCelebi::new().quantize(&pixels, max_colors)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quantize_rgba8_skips_transparent() {
+        #[rustfmt::skip]
+        let bytes = [
+            255, 0, 0, 255,   255, 0, 0, 128,
+            0, 0, 255, 255,   0, 255, 0, 0,
+        ];
+        let result = quantize_rgba8(&bytes, 4);
+
+        assert_eq!(result.color_to_count.values().sum::<u32>(), 3);
+        assert_eq!(result.color_to_count.get(&Argb(0xFFFF0000)), Some(&2));
+        assert_eq!(result.color_to_count.get(&Argb(0xFF0000FF)), Some(&1));
+        assert!(!result.color_to_count.contains_key(&Argb(0xFF00FF00)));
+    }
+
+    #[test]
+    fn test_quantize_rgba16() {
+        let red = [[u16::MAX, 0, 0, u16::MAX]; 8];
+        let result = quantize_rgba16(&red, 4);
+        assert_eq!(result.color_to_count.len(), 1);
+        assert_eq!(result.color_to_count.get(&Argb(0xFFFF0000)), Some(&8));
+
+        let mixed = [[0x8080, 0x0101, 0xFFFF, 0xFFFF], [0, 0, 0, 0]];
+        let result = quantize_rgba16(&mixed, 4);
+        assert_eq!(result.color_to_count.get(&Argb(0xFF8001FF)), Some(&1));
+    }
+}
@@ -1,3 +1,4 @@
+use crate::hct::cam16::Cam16;
 use crate::utils::color_utils::Argb;
 use indexmap::IndexMap;
 
@@ -6,16 +7,158 @@ use indexmap::IndexMap;
 pub struct QuantizerResult {
     /// Map with keys of colors in ARGB format, values of how many of the input pixels belong to the color.
     pub color_to_count: IndexMap<Argb, u32>,
+    /// Map from every distinct input pixel to the color it was quantized to. Only filled in when
+    /// requested with [`QuantizeConfig::return_input_pixel_to_cluster`].
+    pub input_pixel_to_cluster_pixel: Option<IndexMap<Argb, Argb>>,
+}
+
+/// Options for [`quantize_with_config`](crate::quantize::quantize_with_config).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QuantizeConfig {
+    /// The number of colors to divide the image into. A lower number of colors may be returned.
+    pub max_colors: usize,
+    /// How many K-Means iterations to run at most; it stops earlier once clusters settle. At
+    /// least one iteration always runs. Defaults to 10, as used by
+    /// [`QuantizerCelebi`](crate::quantize::QuantizerCelebi).
+    pub max_iterations: usize,
+    /// Whether to fill in [`QuantizerResult::input_pixel_to_cluster_pixel`], e.g. to posterize
+    /// the input image.
+    pub return_input_pixel_to_cluster: bool,
+}
+
+impl Default for QuantizeConfig {
+    fn default() -> Self {
+        Self {
+            max_colors: 128,
+            max_iterations: 10,
+            return_input_pixel_to_cluster: false,
+        }
+    }
 }
 
 impl QuantizerResult {
     #[must_use]
     pub const fn new(color_to_count: IndexMap<Argb, u32>) -> Self {
-        Self { color_to_count }
+        Self {
+            color_to_count,
+            input_pixel_to_cluster_pixel: None,
+        }
+    }
+
+    /// Colors with their pixel counts, most common first. Ties keep the quantizer's order.
+    #[must_use]
+    pub fn sorted_by_population(&self) -> Vec<(Argb, u32)> {
+        let mut sorted: Vec<(Argb, u32)> = self
+            .color_to_count
+            .iter()
+            .map(|(&argb, &count)| (argb, count))
+            .collect();
+        sorted.sort_by_key(|&(_, count)| std::cmp::Reverse(count));
+        sorted
+    }
+
+    /// The most common color, or `None` if the result is empty.
+    #[must_use]
+    pub fn dominant(&self) -> Option<Argb> {
+        self.color_to_count
+            .iter()
+            .rev()
+            .max_by_key(|&(_, &count)| count)
+            .map(|(&argb, _)| argb)
+    }
+
+    /// Collapses colors that are within `cam16_threshold` of each other in CAM16-UCS.
+    ///
+    /// Colors are visited from most to least populous, and each one is folded into the first
+    /// already kept color within the threshold, so the more populous color of a merged group is
+    /// kept and receives the group's summed count. Kept colors stay in their original order.
+    /// Pixels in [`QuantizerResult::input_pixel_to_cluster_pixel`] follow their merged color.
+    pub fn merge_similar(&mut self, cam16_threshold: f64) {
+        let mut kept: Vec<(Argb, Cam16, u32)> = Vec::new();
+        let mut merged_into: IndexMap<Argb, Argb> = IndexMap::new();
+        for (argb, count) in self.sorted_by_population() {
+            let cam = Cam16::from_argb(argb);
+            match kept
+                .iter_mut()
+                .find(|(_, kept_cam, _)| kept_cam.distance(&cam) <= cam16_threshold)
+            {
+                Some((kept_argb, _, total)) => {
+                    *total += count;
+                    merged_into.insert(argb, *kept_argb);
+                }
+                None => kept.push((argb, cam, count)),
+            }
+        }
+        if let Some(pixel_to_cluster) = &mut self.input_pixel_to_cluster_pixel {
+            for cluster in pixel_to_cluster.values_mut() {
+                if let Some(&kept_argb) = merged_into.get(cluster) {
+                    *cluster = kept_argb;
+                }
+            }
+        }
+
+        let totals: IndexMap<Argb, u32> = kept
+            .into_iter()
+            .map(|(argb, _, total)| (argb, total))
+            .collect();
+        self.color_to_count.retain(|argb, count| {
+            totals.get(argb).is_some_and(|&total| {
+                *count = total;
+                true
+            })
+        });
     }
 }
 
 /// An interface to allow use of different quantization techniques.
+///
+/// Implemented by [`QuantizerWu`](crate::quantize::QuantizerWu) (fast, greedy box cutting),
+/// [`QuantizerCelebi`](crate::quantize::QuantizerCelebi) (Wu followed by K-Means refinement) and
+/// [`QuantizerMap`](crate::quantize::QuantizerMap) (exact pixel counts, no reduction).
 pub trait Quantizer {
     fn quantize(&mut self, pixels: &[Argb], max_colors: usize) -> QuantizerResult;
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quantize::QuantizerMap;
+
+    #[test]
+    fn test_sorted_by_population_and_dominant() {
+        let red = Argb(0xffff0000);
+        let blue = Argb(0xff0000ff);
+        let mut pixels = vec![red; 90];
+        pixels.extend(std::iter::repeat_n(blue, 10));
+
+        let result = QuantizerMap::new().quantize(&pixels, 16);
+        assert_eq!(result.dominant(), Some(red));
+        assert_eq!(result.sorted_by_population(), vec![(red, 90), (blue, 10)]);
+        assert_eq!(QuantizerResult::default().dominant(), None);
+    }
+
+    #[test]
+    fn test_merge_similar() {
+        let base = Argb::from_rgb(123, 45, 67);
+        let nudged = Argb::from_rgb(124, 45, 67);
+        let far = Argb::from_rgb(20, 200, 240);
+        let distance = Cam16::from_argb(base).distance(&Cam16::from_argb(nudged));
+        assert!(distance < 2.0, "{distance}");
+
+        let mut result = QuantizerResult::new(IndexMap::from([(nudged, 10), (base, 30), (far, 5)]));
+        let pixel = Argb::from_rgb(125, 46, 67);
+        result.input_pixel_to_cluster_pixel = Some(IndexMap::from([(pixel, nudged), (far, far)]));
+        result.merge_similar(2.0);
+        assert_eq!(
+            result.color_to_count,
+            IndexMap::from([(base, 40), (far, 5)])
+        );
+        assert_eq!(
+            result.input_pixel_to_cluster_pixel,
+            Some(IndexMap::from([(pixel, base), (far, far)]))
+        );
+
+        result.merge_similar(0.5);
+        assert_eq!(result.color_to_count.len(), 2);
+    }
+}
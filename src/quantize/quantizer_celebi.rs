@@ -1,4 +1,4 @@
-use crate::quantize::quantizer::{Quantizer, QuantizerResult};
+use crate::quantize::quantizer::{QuantizeConfig, Quantizer, QuantizerResult};
 use crate::quantize::quantizer_wsmeans::QuantizerWsmeans;
 use crate::quantize::quantizer_wu::QuantizerWu;
 use crate::utils::color_utils::Argb;
@@ -43,6 +43,36 @@ impl Quantizer for QuantizerCelebi {
     }
 }
 
+/// Quantizes `pixels` like [`QuantizerCelebi`], seeding the K-Means step with `seed`.
+///
+/// Quantization is already deterministic for a given input; the seed only picks a different, but
+/// equally reproducible, initial cluster assignment. Useful to pin results in snapshot tests.
+#[must_use]
+pub fn quantize_seeded(pixels: &[Argb], max_colors: usize, seed: u64) -> QuantizerResult {
+    let mut wu = QuantizerWu::new();
+    let wu_result = wu.quantize(pixels, max_colors);
+
+    let starting_clusters: Vec<Argb> = wu_result.color_to_count.keys().copied().collect();
+
+    let clusters = QuantizerWsmeans::quantize_seeded(pixels, &starting_clusters, max_colors, seed);
+    QuantizerResult::new(clusters)
+}
+
+/// Quantizes `pixels` like [`QuantizerCelebi`], with the color count, K-Means iteration cap and
+/// output taken from `config`.
+///
+/// With [`QuantizeConfig::return_input_pixel_to_cluster`] set, every input pixel can be looked up
+/// in [`QuantizerResult::input_pixel_to_cluster_pixel`] to posterize the image.
+#[must_use]
+pub fn quantize_with_config(pixels: &[Argb], config: &QuantizeConfig) -> QuantizerResult {
+    let mut wu = QuantizerWu::new();
+    let wu_result = wu.quantize(pixels, config.max_colors);
+
+    let starting_clusters: Vec<Argb> = wu_result.color_to_count.keys().copied().collect();
+
+    QuantizerWsmeans::quantize_with_config(pixels, &starting_clusters, config)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -99,4 +129,62 @@ mod tests {
         assert_eq!(result.color_to_count.len(), 1);
         assert!(result.color_to_count.contains_key(&Argb(0xFFFF0000)));
     }
+
+    #[test]
+    fn test_quantize_seeded_is_reproducible() {
+        let pixels: Vec<Argb> = (0..2000u32)
+            .map(|i| Argb(0xFF00_0000 | (i.wrapping_mul(2_654_435_761) & 0x00FF_FFFF)))
+            .collect();
+
+        let first = quantize_seeded(&pixels, 16, 7);
+        let second = quantize_seeded(&pixels, 16, 7);
+        assert_eq!(
+            first.color_to_count.iter().collect::<Vec<_>>(),
+            second.color_to_count.iter().collect::<Vec<_>>()
+        );
+
+        let default = QuantizerCelebi::new().quantize(&pixels, 16);
+        let default_seed = quantize_seeded(&pixels, 16, QuantizerWsmeans::DEFAULT_SEED);
+        assert_eq!(
+            default.color_to_count.iter().collect::<Vec<_>>(),
+            default_seed.color_to_count.iter().collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_quantize_with_config_posterize() {
+        let pixels: Vec<Argb> = (0..32u8)
+            .flat_map(|y| (0..32u8).map(move |x| Argb::from_rgb(x * 8, y * 8, 255 - x * 4)))
+            .collect();
+        let config = QuantizeConfig {
+            max_colors: 8,
+            return_input_pixel_to_cluster: true,
+            ..QuantizeConfig::default()
+        };
+        let result = quantize_with_config(&pixels, &config);
+        let pixel_to_cluster = result
+            .input_pixel_to_cluster_pixel
+            .as_ref()
+            .expect("assignments were requested");
+
+        let posterized: Vec<Argb> = pixels.iter().map(|p| pixel_to_cluster[p]).collect();
+        let distinct: std::collections::HashSet<Argb> = posterized.iter().copied().collect();
+        assert_eq!(distinct.len(), config.max_colors);
+        for color in &distinct {
+            let count = posterized.iter().filter(|&p| p == color).count();
+            assert_eq!(result.color_to_count[color] as usize, count);
+        }
+
+        let default = quantize_with_config(&pixels, &QuantizeConfig::default());
+        assert!(default.input_pixel_to_cluster_pixel.is_none());
+        let one_pass = QuantizeConfig {
+            max_iterations: 1,
+            ..config
+        };
+        let one_pass = quantize_with_config(&pixels, &one_pass);
+        assert_eq!(
+            one_pass.color_to_count.values().sum::<u32>() as usize,
+            pixels.len()
+        );
+    }
 }
@@ -0,0 +1,72 @@
+use crate::quantize::quantizer::QuantizerResult;
+use crate::quantize::quantizer_wsmeans::QuantizerWsmeans;
+use crate::quantize::quantizer_wu::QuantizerWu;
+use crate::utils::color_utils::Argb;
+use indexmap::IndexMap;
+
+/// Quantizes an image that arrives in pieces, e.g. tiles or scanlines of a large photo.
+///
+/// Only a histogram of distinct colors is kept between calls to [`StreamingQuantizer::push`], so
+/// the full image never has to be held in memory. [`StreamingQuantizer::finish`] then runs the
+/// same Wu + Wsmeans pipeline as [`QuantizerCelebi`](crate::quantize::QuantizerCelebi), and gives
+/// the same result as quantizing all pushed pixels at once.
+#[derive(Debug, Clone, Default)]
+pub struct StreamingQuantizer {
+    color_to_count: IndexMap<Argb, u32>,
+}
+
+impl StreamingQuantizer {
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a batch of pixels to the histogram.
+    pub fn push(&mut self, pixels: &[Argb]) {
+        for &pixel in pixels {
+            *self.color_to_count.entry(pixel).or_insert(0) += 1;
+        }
+    }
+
+    /// Quantizes all pixels pushed so far into at most `max_colors` colors.
+    #[must_use]
+    pub fn finish(self, max_colors: usize) -> QuantizerResult {
+        let wu_result = QuantizerWu::new().quantize_histogram(&self.color_to_count, max_colors);
+        let starting_clusters: Vec<Argb> = wu_result.color_to_count.keys().copied().collect();
+        QuantizerResult::new(QuantizerWsmeans::quantize_histogram(
+            &self.color_to_count,
+            &starting_clusters,
+            max_colors,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::quantize::{Quantizer, QuantizerCelebi};
+
+    #[test]
+    fn test_streaming_matches_whole() {
+        let pixels: Vec<Argb> = (0..64u32)
+            .flat_map(|y| {
+                (0..64u32).map(move |x| Argb::from_rgb((x * 4) as u8, (y * 4) as u8, 128))
+            })
+            .collect();
+        let (top, bottom) = pixels.split_at(pixels.len() / 2);
+
+        let mut halves = StreamingQuantizer::new();
+        halves.push(top);
+        halves.push(bottom);
+
+        let mut whole = StreamingQuantizer::new();
+        whole.push(&pixels);
+
+        let halves = halves.finish(16).color_to_count;
+        assert_eq!(halves, whole.finish(16).color_to_count);
+        assert_eq!(
+            halves,
+            QuantizerCelebi::new().quantize(&pixels, 16).color_to_count
+        );
+    }
+}
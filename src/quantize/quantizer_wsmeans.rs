@@ -1,5 +1,6 @@
 use crate::quantize::point_provider::PointProvider;
 use crate::quantize::point_provider_lab::PointProviderLab;
+use crate::quantize::quantizer::{QuantizeConfig, QuantizerResult};
 use crate::utils::color_utils::Argb;
 use indexmap::IndexMap;
 
@@ -19,11 +20,19 @@ impl Default for Distance {
 /// reduces the number of comparisons needed to identify which cluster a point should be moved to.
 ///
 /// Wsmeans stands for Weighted Square Means.
+///
+/// With the `rayon` feature enabled, the point-to-cluster assignment step runs in parallel. Cluster
+/// sums are still accumulated in input order, so the output is identical to the serial path.
 pub struct QuantizerWsmeans;
 
 impl QuantizerWsmeans {
-    const MAX_ITERATIONS: usize = 10;
+    /// Iterations used by [`QuantizerWsmeans::quantize`] before giving up on convergence.
+    pub const DEFAULT_MAX_ITERATIONS: usize = 10;
     const MIN_MOVEMENT_DISTANCE: f64 = 3.0;
+    /// Seed for the initial random cluster assignment used by [`QuantizerWsmeans::quantize`].
+    pub const DEFAULT_SEED: u64 = 0x42688;
+    #[cfg(feature = "rayon")]
+    const PARALLEL_MIN_LEN: usize = 1024;
 
     #[must_use]
     pub fn quantize(
@@ -31,24 +40,151 @@ impl QuantizerWsmeans {
         starting_clusters: &[Argb],
         max_colors: usize,
     ) -> IndexMap<Argb, u32> {
-        let mut random = Random::new(0x42688);
-        let point_provider = PointProviderLab;
+        Self::quantize_impl(
+            input_pixels,
+            starting_clusters,
+            max_colors,
+            cfg!(feature = "rayon"),
+        )
+    }
+
+    /// Like [`QuantizerWsmeans::quantize`], but takes pixels that were already counted, e.g. by
+    /// [`QuantizerMap`](crate::quantize::QuantizerMap) or
+    /// [`StreamingQuantizer`](crate::quantize::StreamingQuantizer).
+    #[must_use]
+    pub fn quantize_histogram(
+        pixel_to_count: &IndexMap<Argb, u32>,
+        starting_clusters: &[Argb],
+        max_colors: usize,
+    ) -> IndexMap<Argb, u32> {
+        Self::quantize_histogram_impl(
+            pixel_to_count,
+            starting_clusters,
+            max_colors,
+            cfg!(feature = "rayon"),
+            Self::DEFAULT_SEED,
+        )
+    }
+
+    /// Like [`QuantizerWsmeans::quantize`], but seeds the initial random cluster assignment with
+    /// `seed` instead of [`QuantizerWsmeans::DEFAULT_SEED`].
+    ///
+    /// The same seed always gives the same result, on every platform and with or without the
+    /// `rayon` feature.
+    #[must_use]
+    pub fn quantize_seeded(
+        input_pixels: &[Argb],
+        starting_clusters: &[Argb],
+        max_colors: usize,
+        seed: u64,
+    ) -> IndexMap<Argb, u32> {
+        let mut pixel_to_count = IndexMap::new();
+        for &pixel in input_pixels {
+            *pixel_to_count.entry(pixel).or_insert(0) += 1;
+        }
+        Self::quantize_histogram_impl(
+            &pixel_to_count,
+            starting_clusters,
+            max_colors,
+            cfg!(feature = "rayon"),
+            seed,
+        )
+    }
 
+    fn quantize_impl(
+        input_pixels: &[Argb],
+        starting_clusters: &[Argb],
+        max_colors: usize,
+        parallel: bool,
+    ) -> IndexMap<Argb, u32> {
         // 1. Deduplicate pixels (preserving insertion order via IndexMap)
         let mut pixel_to_count = IndexMap::new();
         for &pixel in input_pixels {
             *pixel_to_count.entry(pixel).or_insert(0) += 1;
         }
 
+        Self::quantize_histogram_impl(
+            &pixel_to_count,
+            starting_clusters,
+            max_colors,
+            parallel,
+            Self::DEFAULT_SEED,
+        )
+    }
+
+    /// Like [`QuantizerWsmeans::quantize`], but with the iteration cap and output taken from
+    /// `config`. When `config.return_input_pixel_to_cluster` is set, the result also maps every
+    /// distinct input pixel to the color of the cluster it ended up in.
+    #[must_use]
+    pub fn quantize_with_config(
+        input_pixels: &[Argb],
+        starting_clusters: &[Argb],
+        config: &QuantizeConfig,
+    ) -> QuantizerResult {
+        let mut pixel_to_count = IndexMap::new();
+        for &pixel in input_pixels {
+            *pixel_to_count.entry(pixel).or_insert(0) += 1;
+        }
+        let clustering = Self::cluster(
+            &pixel_to_count,
+            starting_clusters,
+            config.max_colors,
+            config.max_iterations,
+            cfg!(feature = "rayon"),
+            Self::DEFAULT_SEED,
+        );
+
+        let mut result = QuantizerResult::new(clustering.color_to_count());
+        if config.return_input_pixel_to_cluster {
+            result.input_pixel_to_cluster_pixel = Some(
+                pixel_to_count
+                    .keys()
+                    .zip(&clustering.assignments)
+                    .map(|(&pixel, &cluster)| (pixel, clustering.colors[cluster]))
+                    .collect(),
+            );
+        }
+        result
+    }
+
+    fn quantize_histogram_impl(
+        pixel_to_count: &IndexMap<Argb, u32>,
+        starting_clusters: &[Argb],
+        max_colors: usize,
+        parallel: bool,
+        seed: u64,
+    ) -> IndexMap<Argb, u32> {
+        Self::cluster(
+            pixel_to_count,
+            starting_clusters,
+            max_colors,
+            Self::DEFAULT_MAX_ITERATIONS,
+            parallel,
+            seed,
+        )
+        .color_to_count()
+    }
+
+    fn cluster(
+        pixel_to_count: &IndexMap<Argb, u32>,
+        starting_clusters: &[Argb],
+        max_colors: usize,
+        max_iterations: usize,
+        parallel: bool,
+        seed: u64,
+    ) -> Clustering {
+        let mut random = Random::new(seed);
+        let point_provider = PointProviderLab;
+
         let point_count = pixel_to_count.len();
         if point_count == 0 {
-            return IndexMap::new();
+            return Clustering::default();
         }
 
         let mut points = Vec::with_capacity(point_count);
         let mut counts = Vec::with_capacity(point_count);
 
-        for (pixel, count) in pixel_to_count {
+        for (&pixel, &count) in pixel_to_count {
             points.push(point_provider.point_from_argb(pixel));
             counts.push(count);
         }
@@ -73,7 +209,7 @@ impl QuantizerWsmeans {
         let mut pixel_count_sums = vec![0u32; cluster_count];
 
         // 3. Main Iteration Loop
-        for iteration in 0..Self::MAX_ITERATIONS {
+        for iteration in 0..max_iterations.max(1) {
             for i in 0..cluster_count {
                 for j in i + 1..cluster_count {
                     let distance = point_provider.distance(clusters[i], clusters[j]);
@@ -87,39 +223,14 @@ impl QuantizerWsmeans {
                 });
             }
 
-            let mut points_moved = 0;
-            for i in 0..point_count {
-                let point = points[i];
-                let previous_cluster_index = cluster_indices[i];
-                let previous_distance =
-                    point_provider.distance(point, clusters[previous_cluster_index]);
-
-                let mut minimum_distance = previous_distance;
-                let mut new_cluster_index = None;
-
-                // CRITICAL: We must maintain this specific loop structure to match original behavior
-                for j in 0..cluster_count {
-                    if distance_to_index_matrix[previous_cluster_index][j].distance
-                        >= 4.0 * previous_distance
-                    {
-                        continue;
-                    }
-                    let distance = point_provider.distance(point, clusters[j]);
-                    if distance < minimum_distance {
-                        minimum_distance = distance;
-                        new_cluster_index = Some(j);
-                    }
-                }
-
-                if let Some(idx) = new_cluster_index {
-                    let distance_change =
-                        (minimum_distance.sqrt() - previous_distance.sqrt()).abs();
-                    if distance_change > Self::MIN_MOVEMENT_DISTANCE {
-                        points_moved += 1;
-                        cluster_indices[i] = idx;
-                    }
-                }
-            }
+            let points_moved = Self::reassign_points(
+                &point_provider,
+                &points,
+                &mut cluster_indices,
+                &clusters,
+                &distance_to_index_matrix,
+                parallel,
+            );
 
             if points_moved == 0 && iteration != 0 {
                 break;
@@ -156,11 +267,117 @@ impl QuantizerWsmeans {
         }
 
         // 5. Final Result Mapping
-        clusters
-            .into_iter()
-            .zip(pixel_count_sums)
-            .filter(|(_, count)| *count > 0)
-            .map(|(cluster, count)| (point_provider.point_to_argb(cluster), count))
+        Clustering {
+            colors: clusters
+                .into_iter()
+                .map(|cluster| point_provider.point_to_argb(cluster))
+                .collect(),
+            counts: pixel_count_sums,
+            assignments: cluster_indices,
+        }
+    }
+
+    /// Moves every point to its nearest cluster, returning how many points moved.
+    #[cfg_attr(not(feature = "rayon"), allow(unused_variables))]
+    fn reassign_points(
+        point_provider: &PointProviderLab,
+        points: &[[f64; 3]],
+        cluster_indices: &mut [usize],
+        clusters: &[[f64; 3]],
+        distance_to_index_matrix: &[Vec<Distance>],
+        parallel: bool,
+    ) -> usize {
+        #[cfg(feature = "rayon")]
+        if parallel {
+            use rayon::prelude::*;
+            return points
+                .par_iter()
+                .zip(cluster_indices.par_iter_mut())
+                .with_min_len(Self::PARALLEL_MIN_LEN)
+                .map(|(&point, cluster_index)| {
+                    Self::reassign_point(
+                        point_provider,
+                        point,
+                        cluster_index,
+                        clusters,
+                        distance_to_index_matrix,
+                    )
+                })
+                .filter(|&moved| moved)
+                .count();
+        }
+
+        points
+            .iter()
+            .zip(cluster_indices.iter_mut())
+            .map(|(&point, cluster_index)| {
+                Self::reassign_point(
+                    point_provider,
+                    point,
+                    cluster_index,
+                    clusters,
+                    distance_to_index_matrix,
+                )
+            })
+            .filter(|&moved| moved)
+            .count()
+    }
+
+    /// Moves a single point to its nearest cluster. Returns `true` if the point moved.
+    fn reassign_point(
+        point_provider: &PointProviderLab,
+        point: [f64; 3],
+        cluster_index: &mut usize,
+        clusters: &[[f64; 3]],
+        distance_to_index_matrix: &[Vec<Distance>],
+    ) -> bool {
+        let previous_cluster_index = *cluster_index;
+        let previous_distance = point_provider.distance(point, clusters[previous_cluster_index]);
+
+        let mut minimum_distance = previous_distance;
+        let mut new_cluster_index = None;
+
+        // CRITICAL: We must maintain this specific loop structure to match original behavior
+        for (j, &cluster) in clusters.iter().enumerate() {
+            if distance_to_index_matrix[previous_cluster_index][j].distance
+                >= 4.0 * previous_distance
+            {
+                continue;
+            }
+            let distance = point_provider.distance(point, cluster);
+            if distance < minimum_distance {
+                minimum_distance = distance;
+                new_cluster_index = Some(j);
+            }
+        }
+
+        if let Some(idx) = new_cluster_index {
+            let distance_change = (minimum_distance.sqrt() - previous_distance.sqrt()).abs();
+            if distance_change > Self::MIN_MOVEMENT_DISTANCE {
+                *cluster_index = idx;
+                return true;
+            }
+        }
+        false
+    }
+}
+
+/// Outcome of a Wsmeans run: the cluster colors and populations, and the cluster every distinct
+/// input pixel was assigned to.
+#[derive(Default)]
+struct Clustering {
+    colors: Vec<Argb>,
+    counts: Vec<u32>,
+    assignments: Vec<usize>,
+}
+
+impl Clustering {
+    fn color_to_count(&self) -> IndexMap<Argb, u32> {
+        self.colors
+            .iter()
+            .zip(&self.counts)
+            .filter(|(_, count)| **count > 0)
+            .map(|(&color, &count)| (color, count))
             .collect()
     }
 }
@@ -209,4 +426,26 @@ mod tests {
         assert!(result.len() <= 2);
         assert_eq!(result.values().sum::<u32>(), 4);
     }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_parallel_matches_serial() {
+        use crate::quantize::{Quantizer, QuantizerWu};
+
+        // A fixed 128x128 gradient image, large enough to be split across threads.
+        let pixels: Vec<Argb> = (0..128u8)
+            .flat_map(|y| (0..128u8).map(move |x| Argb::from_rgb(x * 2, y * 2, 255 - x - y / 2)))
+            .collect();
+        let starting_clusters: Vec<Argb> = QuantizerWu::new()
+            .quantize(&pixels, 16)
+            .color_to_count
+            .keys()
+            .copied()
+            .collect();
+
+        let serial = QuantizerWsmeans::quantize_impl(&pixels, &starting_clusters, 16, false);
+        let parallel = QuantizerWsmeans::quantize_impl(&pixels, &starting_clusters, 16, true);
+        assert_eq!(serial, parallel);
+        assert_eq!(parallel.values().sum::<u32>(), 128 * 128);
+    }
 }
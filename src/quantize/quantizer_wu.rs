@@ -42,6 +42,27 @@ impl QuantizerWu {
         Self::default()
     }
 
+    /// Like [`Quantizer::quantize`], but takes pixels that were already counted.
+    #[must_use]
+    pub fn quantize_histogram(
+        &mut self,
+        color_to_count: &IndexMap<Argb, u32>,
+        max_colors: usize,
+    ) -> QuantizerResult {
+        self.construct_histogram(color_to_count);
+        self.create_moments();
+        let create_boxes_result = self.create_boxes(max_colors);
+
+        let colors = self.create_result(create_boxes_result.result_count as usize);
+
+        let mut result_map = IndexMap::new();
+        for color in colors {
+            result_map.insert(color, 0);
+        }
+
+        QuantizerResult::new(result_map)
+    }
+
     fn construct_histogram(&mut self, pixels: &IndexMap<Argb, u32>) {
         self.weights.fill(0);
         self.moments_r.fill(0);
@@ -471,19 +492,7 @@ impl Quantizer for QuantizerWu {
     fn quantize(&mut self, pixels: &[Argb], max_colors: usize) -> QuantizerResult {
         let mut map_quantizer = QuantizerMap::new();
         let map_result = map_quantizer.quantize(pixels, max_colors);
-
-        self.construct_histogram(&map_result.color_to_count);
-        self.create_moments();
-        let create_boxes_result = self.create_boxes(max_colors);
-
-        let colors = self.create_result(create_boxes_result.result_count as usize);
-
-        let mut result_map = IndexMap::new();
-        for color in colors {
-            result_map.insert(color, 0);
-        }
-
-        QuantizerResult::new(result_map)
+        self.quantize_histogram(&map_result.color_to_count, max_colors)
     }
 }
 
@@ -519,6 +528,24 @@ mod tests {
         assert!(color.green() < 10);
         assert!(color.blue() < 10);
     }
+
+    #[test]
+    fn test_quantize_wu_gradient_against_celebi() {
+        let pixels: Vec<Argb> = (0..=255u8).map(|v| Argb::from_rgb(v, v, 255 - v)).collect();
+
+        let mut wu = QuantizerWu::new();
+        let mut celebi = crate::quantize::QuantizerCelebi::new();
+        let quantizers: [&mut dyn Quantizer; 2] = [&mut wu, &mut celebi];
+        let counts: Vec<usize> = quantizers
+            .into_iter()
+            .map(|quantizer| quantizer.quantize(&pixels, 8).color_to_count.len())
+            .collect();
+
+        // Wu always fills the requested number of boxes on a smooth gradient; Celebi refines
+        // those boxes and may merge some of them.
+        assert_eq!(counts[0], 8);
+        assert!(counts[1] > 0 && counts[1] <= counts[0]);
+    }
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -1,3 +1,10 @@
+//! Constructors for each [`Variant`](crate::dynamic::variant::Variant).
+//!
+//! The scheme types are not wrappers: `SchemeTonalSpot::builder(source, is_dark, contrast).build()`
+//! and its siblings return a [`DynamicScheme`](crate::dynamic::dynamic_scheme::DynamicScheme)
+//! directly, identical to [`DynamicScheme::from_source_colors`] with the matching variant.
+//!
+//! [`DynamicScheme::from_source_colors`]: crate::dynamic::dynamic_scheme::DynamicScheme::from_source_colors
 #![allow(clippy::new_ret_no_self)]
 
 pub mod scheme_cmf;
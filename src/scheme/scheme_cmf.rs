@@ -6,6 +6,10 @@ use crate::palettes::tonal_palette::TonalPalette;
 use bon::bon;
 
 /// A Dynamic Color theme with 2 source colors.
+///
+/// Build one with `SchemeCmf::builder(source, is_dark, contrast_level).build()`. Unlike the other
+/// schemes it defaults to [`SpecVersion::Spec2026`], the first spec with CMF-specific surface
+/// tones; the optional second source color (via `additional_colors`) drives the tertiary palette.
 pub struct SchemeCmf;
 
 #[bon]
@@ -78,3 +82,33 @@ impl SchemeCmf {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::material_dynamic_colors::MaterialDynamicColors;
+    use crate::scheme::SchemeTonalSpot;
+    use crate::utils::color_utils::Argb;
+
+    #[test]
+    fn test_cmf_surfaces() {
+        let source = Hct::from_argb(Argb(0xFF4285F4));
+        let colors = MaterialDynamicColors::new();
+
+        let light = SchemeCmf::builder(source, false, 0.0).build();
+        assert_eq!(light.spec_version, SpecVersion::Spec2026);
+        assert_eq!(light.variant, Variant::Cmf);
+        assert!((light.get_tone(&colors.surface()) - 98.0).abs() < 1e-9);
+
+        let dark = SchemeCmf::builder(source, true, 0.0).build();
+        assert!((dark.get_tone(&colors.surface()) - 4.0).abs() < 1e-9);
+
+        let tonal_spot = SchemeTonalSpot::builder(source, false, 0.0)
+            .spec_version(SpecVersion::Spec2026)
+            .build();
+        assert_ne!(
+            light.get_argb(&colors.surface()),
+            tonal_spot.get_argb(&colors.surface())
+        );
+    }
+}
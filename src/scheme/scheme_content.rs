@@ -6,6 +6,10 @@ use crate::hct::hct_color::Hct;
 use bon::bon;
 
 /// A scheme that places the source color in `Scheme.primaryContainer`.
+///
+/// The 2025 and 2026 specs do not define a Content variant, so the whole scheme falls back to
+/// the 2021 spec: palettes and roles resolve as 2021 and `spec_version` reports
+/// [`SpecVersion::Spec2021`], while `requested_spec_version` keeps the version asked for.
 pub struct SchemeContent;
 
 #[bon]
@@ -78,3 +82,37 @@ impl SchemeContent {
         scheme
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::material_dynamic_colors::MaterialDynamicColors;
+    use crate::utils::color_utils::Argb;
+
+    #[test]
+    fn test_primary_palette_keeps_source_chroma() {
+        let source = Hct::from_argb(Argb(0xFFE8590C));
+        let colors = MaterialDynamicColors::new_with_spec(SpecVersion::Spec2021);
+        for &spec_version in SpecVersion::all() {
+            for is_dark in [false, true] {
+                let scheme = SchemeContent::builder(source, is_dark, 0.0)
+                    .spec_version(spec_version)
+                    .build();
+                assert_eq!(scheme.variant, Variant::Content);
+                assert_eq!(scheme.spec_version, SpecVersion::Spec2021);
+                assert_eq!(scheme.requested_spec_version, spec_version);
+                assert!((scheme.primary_palette.chroma - source.chroma()).abs() < 1e-9);
+                assert!((scheme.primary_palette.hue - source.hue()).abs() < 1e-9);
+
+                for color in colors
+                    .all_dynamic_colors()
+                    .iter()
+                    .filter_map(|getter| getter())
+                {
+                    let tone = scheme.get_tone(&color);
+                    assert!((0.0..=100.0).contains(&tone), "{}", color.name);
+                }
+            }
+        }
+    }
+}
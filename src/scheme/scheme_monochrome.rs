@@ -3,6 +3,7 @@ use crate::dynamic::color_specs::ColorSpecs;
 use crate::dynamic::dynamic_scheme::DynamicScheme;
 use crate::dynamic::variant::Variant;
 use crate::hct::hct_color::Hct;
+use crate::utils::color_utils::Argb;
 use bon::bon;
 
 /// A monochrome theme, colors are purely black / white / gray.
@@ -77,4 +78,56 @@ impl SchemeMonochrome {
         scheme.source_color_hct_list = source_color_hct_list;
         scheme
     }
+
+    /// Builds a greyscale scheme from any seed, using the default spec version and platform.
+    ///
+    /// Only the seed's tone matters for the neutral roles; the error roles keep their color.
+    #[must_use]
+    pub fn from_argb(argb: Argb, is_dark: bool, contrast_level: f64) -> DynamicScheme {
+        Self::builder(argb, is_dark, contrast_level).build()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::material_dynamic_colors::MaterialDynamicColors;
+
+    #[test]
+    fn test_from_argb_is_greyscale() {
+        let colors = MaterialDynamicColors::new();
+        for is_dark in [false, true] {
+            let scheme = SchemeMonochrome::from_argb(Argb(0xFFEA4335), is_dark, 0.0);
+            for palette in [
+                &scheme.primary_palette,
+                &scheme.secondary_palette,
+                &scheme.tertiary_palette,
+                &scheme.neutral_palette,
+                &scheme.neutral_variant_palette,
+            ] {
+                assert!(
+                    palette.chroma() == 0.0,
+                    "palette chroma {}",
+                    palette.chroma()
+                );
+            }
+            // sRGB greys keep a CAM16 chroma of up to about 3.
+            for color in colors
+                .all_dynamic_colors()
+                .iter()
+                .filter_map(|getter| getter())
+            {
+                if color.name.contains("error") {
+                    continue;
+                }
+                let hct = Hct::from_argb(scheme.get_argb(&color));
+                assert!(
+                    hct.chroma() < 3.0,
+                    "{} has chroma {}",
+                    color.name,
+                    hct.chroma()
+                );
+            }
+        }
+    }
 }
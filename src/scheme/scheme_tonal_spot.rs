@@ -78,3 +78,37 @@ impl SchemeTonalSpot {
         scheme
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::dynamic::material_dynamic_colors::MaterialDynamicColors;
+    use crate::utils::color_utils::Argb;
+
+    #[test]
+    fn test_matches_generic_dynamic_scheme() {
+        let source = Hct::from_argb(Argb(0xFF4285F4));
+        let colors = MaterialDynamicColors::new();
+        for spec_version in SpecVersion::all().iter().copied() {
+            let named: DynamicScheme = SchemeTonalSpot::builder(source, true, 0.5)
+                .spec_version(spec_version)
+                .build();
+            let generic = DynamicScheme::from_source_colors(
+                &[source],
+                Variant::TonalSpot,
+                true,
+                0.5,
+                Platform::Phone,
+                spec_version,
+            );
+            assert_eq!(named, generic);
+            for color in colors
+                .all_dynamic_colors()
+                .iter()
+                .filter_map(|getter| getter())
+            {
+                assert_eq!(named.get_argb(&color), generic.get_argb(&color));
+            }
+        }
+    }
+}
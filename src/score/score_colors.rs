@@ -9,6 +9,33 @@ struct ScoredHct {
     score: f64,
 }
 
+/// Options for [`Score::score_with_options`]. The defaults match those of [`Score::score`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScoreOptions {
+    /// Max count of colors to be returned.
+    pub desired_count: usize,
+    /// Color to return if no suitable colors are found.
+    pub fallback_color_argb: Argb,
+    /// Whether to filter out colors with too little chroma or too small a share of the image.
+    pub filter: bool,
+    /// Colors darker than this tone are never returned. Defaults to `0.0`.
+    pub min_tone: f64,
+    /// Colors lighter than this tone are never returned. Defaults to `100.0`.
+    pub max_tone: f64,
+}
+
+impl Default for ScoreOptions {
+    fn default() -> Self {
+        Self {
+            desired_count: 4,
+            fallback_color_argb: Argb(0xff4285f4),
+            filter: true,
+            min_tone: 0.0,
+            max_tone: 100.0,
+        }
+    }
+}
+
 /// Given a large set of colors, remove colors that are unsuitable for a UI theme, and rank the rest
 /// based on suitability.
 ///
@@ -36,6 +63,9 @@ impl Score {
     /// * `fallback_color_argb`: color to be returned if no other options available.
     ///   Defaults to Google Blue (`0xff4285f4`).
     /// * `filter`: whether to filter out undesirable combinations. Defaults to `true`.
+    /// * `min_tone`, `max_tone`: colors outside this tone band are dropped before ranking, whether
+    ///   or not `filter` is set. They still count towards the population of their hue. Default to
+    ///   `0.0` and `100.0`.
     ///
     /// # Returns
     ///
@@ -58,30 +88,184 @@ impl Score {
         /// Whether to filter out undesirable combinations. Defaults to `true`.
         #[builder(default = true)]
         filter: bool,
+        /// Colors darker than this tone are dropped. Defaults to `0.0`.
+        #[builder(default = 0.0)]
+        min_tone: f64,
+        /// Colors lighter than this tone are dropped. Defaults to `100.0`.
+        #[builder(default = 100.0)]
+        max_tone: f64,
+    ) -> Vec<Argb> {
+        let populations = colors_to_population
+            .iter()
+            .map(|(&argb, &population)| (argb, f64::from(population)));
+        let Some(scored_hcts) = Self::scored_hcts(populations, filter, min_tone, max_tone) else {
+            return vec![fallback_color_argb];
+        };
+        Self::select_spread(&scored_hcts, desired_count, fallback_color_argb)
+    }
+
+    /// Like [`Score::score_with_options`], but with fractional weights in place of pixel counts.
+    ///
+    /// The weights are used as the population in the scoring math, so callers can e.g. weight
+    /// pixels near the center of a wallpaper more heavily than those in the corners. Negative and
+    /// NaN weights count as zero.
+    #[must_use]
+    pub fn score_weighted(
+        colors_with_weights: &IndexMap<Argb, f64>,
+        options: ScoreOptions,
     ) -> Vec<Argb> {
-        let mut hue_population = [0u32; 360];
+        let populations = colors_with_weights
+            .iter()
+            .map(|(&argb, &weight)| (argb, weight.max(0.0)));
+        let Some(scored_hcts) = Self::scored_hcts(
+            populations,
+            options.filter,
+            options.min_tone,
+            options.max_tone,
+        ) else {
+            return vec![options.fallback_color_argb];
+        };
+        Self::select_spread(
+            &scored_hcts,
+            options.desired_count,
+            options.fallback_color_argb,
+        )
+    }
+
+    /// Like [`Score::score_with_options`], but pairs every chosen color with its population in
+    /// `colors_to_population`, e.g. to size swatches by how common each color is.
+    ///
+    /// When no color is suitable, the fallback color is returned with a count of `0`.
+    #[must_use]
+    pub fn score_with_counts(
+        colors_to_population: &IndexMap<Argb, u32>,
+        options: ScoreOptions,
+    ) -> Vec<(Argb, u32)> {
+        let populations = colors_to_population
+            .iter()
+            .map(|(&argb, &population)| (argb, f64::from(population)));
+        let scored_hcts = Self::scored_hcts(
+            populations,
+            options.filter,
+            options.min_tone,
+            options.max_tone,
+        )
+        .unwrap_or_default();
+        if scored_hcts.is_empty() {
+            return vec![(options.fallback_color_argb, 0)];
+        }
+        Self::select_spread(
+            &scored_hcts,
+            options.desired_count,
+            options.fallback_color_argb,
+        )
+        .into_iter()
+        .map(|argb| (argb, colors_to_population.get(&argb).copied().unwrap_or(0)))
+        .collect()
+    }
+
+    /// Picks up to `desired_count` colors from `scored_hcts`, best first, whose hues are spread as
+    /// far apart as possible.
+    fn select_spread(
+        scored_hcts: &[ScoredHct],
+        desired_count: usize,
+        fallback_color_argb: Argb,
+    ) -> Vec<Argb> {
+        // 4. Selection Logic (The greedy spread)
+        let mut chosen_colors: Vec<Hct> = Vec::with_capacity(desired_count);
+        for difference_degrees in (15..=90).rev() {
+            chosen_colors.clear();
+            for entry in scored_hcts {
+                let has_duplicate = chosen_colors.iter().any(|chosen| {
+                    MathUtils::difference_degrees(entry.hct.hue(), chosen.hue())
+                        < f64::from(difference_degrees)
+                });
+
+                if !has_duplicate {
+                    chosen_colors.push(entry.hct);
+                }
+                if chosen_colors.len() >= desired_count {
+                    break;
+                }
+            }
+            if chosen_colors.len() >= desired_count {
+                break;
+            }
+        }
+
+        if chosen_colors.is_empty() {
+            return vec![fallback_color_argb];
+        }
+
+        chosen_colors.into_iter().map(|h| h.to_argb()).collect()
+    }
+
+    /// Ranks every candidate color by its score, highest first, without picking a spread of hues.
+    ///
+    /// The score combines how much of the input shares the color's hue with how close its chroma
+    /// is to the target chroma. With `options.filter` set, colors that would be rejected by
+    /// [`Score::score`] are left out. `desired_count` and `fallback_color_argb` are not used.
+    #[must_use]
+    pub fn ranked(
+        colors_to_population: &IndexMap<Argb, u32>,
+        options: ScoreOptions,
+    ) -> Vec<(Argb, f64)> {
+        let populations = colors_to_population
+            .iter()
+            .map(|(&argb, &population)| (argb, f64::from(population)));
+        Self::scored_hcts(
+            populations,
+            options.filter,
+            options.min_tone,
+            options.max_tone,
+        )
+        .unwrap_or_default()
+        .into_iter()
+        .map(|scored| (scored.hct.to_argb(), scored.score))
+        .collect()
+    }
+
+    /// Returns `true` when every populated color is below the chroma cutoff used by
+    /// [`Score::score`], i.e. when scoring with filtering would fall back to the fallback color
+    /// because the input is effectively greyscale. An empty input counts as monochromatic.
+    #[must_use]
+    pub fn is_monochromatic(colors_to_population: &IndexMap<Argb, u32>) -> bool {
+        colors_to_population
+            .iter()
+            .filter(|&(_, &population)| population > 0)
+            .all(|(&argb, _)| Hct::from_argb(argb).chroma() < Self::CUTOFF_CHROMA)
+    }
+
+    /// Scores the colors and sorts them by score, highest first. Returns `None` when the input has
+    /// no population at all.
+    fn scored_hcts(
+        colors_to_population: impl Iterator<Item = (Argb, f64)>,
+        filter: bool,
+        min_tone: f64,
+        max_tone: f64,
+    ) -> Option<Vec<ScoredHct>> {
+        let mut hue_population = [0.0; 360];
         let mut population_sum = 0.0;
 
         // 1. Create HCTs and populate hue data
         let colors_hct: Vec<Hct> = colors_to_population
-            .iter()
-            .map(|(&argb, &population)| {
+            .map(|(argb, population)| {
                 let hct = Hct::from_argb(argb);
                 let hue = MathUtils::sanitize_degrees_int(hct.hue().floor() as i32) as usize;
                 hue_population[hue] += population;
-                population_sum += f64::from(population);
+                population_sum += population;
                 hct
             })
             .collect();
 
         if population_sum == 0.0 {
-            return vec![fallback_color_argb];
+            return None;
         }
 
         // 2. Calculate excited proportions (Exact neighborhood logic)
         let mut hue_excited_proportions = [0.0; 360];
         for (hue, &pop) in hue_population.iter().enumerate() {
-            let proportion = f64::from(pop) / population_sum;
+            let proportion = pop / population_sum;
             for i in (hue as i32 - 14)..(hue as i32 + 16) {
                 let neighbor_hue = MathUtils::sanitize_degrees_int(i) as usize;
                 hue_excited_proportions[neighbor_hue] += proportion;
@@ -95,6 +279,9 @@ impl Score {
                 let hue = MathUtils::sanitize_degrees_int(hct.hue().round() as i32) as usize;
                 let proportion = hue_excited_proportions[hue];
 
+                if hct.tone() < min_tone || hct.tone() > max_tone {
+                    return None;
+                }
                 if filter
                     && (hct.chroma() < Self::CUTOFF_CHROMA
                         || proportion <= Self::CUTOFF_EXCITED_PROPORTION)
@@ -123,33 +310,22 @@ impl Score {
                 .unwrap_or(std::cmp::Ordering::Equal)
         });
 
-        // 4. Selection Logic (The greedy spread)
-        let mut chosen_colors: Vec<Hct> = Vec::with_capacity(desired_count);
-        for difference_degrees in (15..=90).rev() {
-            chosen_colors.clear();
-            for entry in &scored_hcts {
-                let has_duplicate = chosen_colors.iter().any(|chosen| {
-                    MathUtils::difference_degrees(entry.hct.hue(), chosen.hue())
-                        < f64::from(difference_degrees)
-                });
-
-                if !has_duplicate {
-                    chosen_colors.push(entry.hct);
-                }
-                if chosen_colors.len() >= desired_count {
-                    break;
-                }
-            }
-            if chosen_colors.len() >= desired_count {
-                break;
-            }
-        }
-
-        if chosen_colors.is_empty() {
-            return vec![fallback_color_argb];
-        }
+        Some(scored_hcts)
+    }
 
-        chosen_colors.into_iter().map(|h| h.to_argb()).collect()
+    /// Same as [`Score::score`], with all options passed in a single [`ScoreOptions`].
+    #[must_use]
+    pub fn score_with_options(
+        colors_to_population: &IndexMap<Argb, u32>,
+        options: ScoreOptions,
+    ) -> Vec<Argb> {
+        Self::score(colors_to_population)
+            .desired_count(options.desired_count)
+            .fallback_color_argb(options.fallback_color_argb)
+            .filter(options.filter)
+            .min_tone(options.min_tone)
+            .max_tone(options.max_tone)
+            .call()
     }
 }
 
@@ -221,4 +397,125 @@ mod tests {
         // Should be filtered out, returning fallback
         assert_eq!(result, vec![fallback]);
     }
+
+    #[test]
+    fn test_score_with_options_no_filter() {
+        let mut colors = IndexMap::new();
+        colors.insert(Argb(0xff111111), 100);
+
+        let filtered = Score::score_with_options(&colors, ScoreOptions::default());
+        assert_eq!(filtered, vec![ScoreOptions::default().fallback_color_argb]);
+
+        let unfiltered = Score::score_with_options(
+            &colors,
+            ScoreOptions {
+                filter: false,
+                ..ScoreOptions::default()
+            },
+        );
+        assert_eq!(unfiltered, vec![Argb(0xff111111)]);
+    }
+
+    #[test]
+    fn test_ranked_matches_score() {
+        let mut colors = IndexMap::new();
+        colors.insert(Argb(0xFFCCDDCC), 50);
+        colors.insert(Argb(0xFF00DD88), 50);
+        colors.insert(Argb(0xFFCCDDEE), 50);
+        colors.insert(Argb(0xFFFF0000), 20);
+
+        let ranked = Score::ranked(&colors, ScoreOptions::default());
+        let scored = Score::score_with_options(&colors, ScoreOptions::default());
+
+        assert_eq!(ranked[0].0, scored[0]);
+        assert!(ranked.windows(2).all(|w| w[0].1 >= w[1].1));
+        assert!(Score::ranked(&IndexMap::new(), ScoreOptions::default()).is_empty());
+    }
+
+    #[test]
+    fn test_is_monochromatic() {
+        let mut greys = IndexMap::new();
+        for level in [0x20u8, 0x60, 0xa0, 0xe0] {
+            greys.insert(Argb::from_rgb(level, level, level), 100);
+        }
+        assert!(Score::is_monochromatic(&greys));
+        assert_eq!(Score::score(&greys).call(), vec![Argb(0xff4285f4)]);
+
+        greys.insert(Argb(0xffea4335), 100);
+        assert!(!Score::is_monochromatic(&greys));
+    }
+
+    #[test]
+    fn test_score_weighted() {
+        let red = Hct::new(30.0, 48.0, 50.0).to_argb();
+        let green = Hct::new(140.0, 40.0, 50.0).to_argb();
+
+        let mut weights = IndexMap::new();
+        weights.insert(red, 1.5);
+        weights.insert(green, 1.5);
+        let result = Score::score_weighted(&weights, ScoreOptions::default());
+        assert_eq!(result, vec![red, green]);
+
+        weights.insert(green, 3.0);
+        let result = Score::score_weighted(&weights, ScoreOptions::default());
+        assert_eq!(result, vec![green, red]);
+
+        let mut counts = IndexMap::new();
+        counts.insert(red, 3);
+        counts.insert(green, 6);
+        assert_eq!(
+            Score::score_with_options(&counts, ScoreOptions::default()),
+            result
+        );
+    }
+
+    #[test]
+    fn test_score_tone_band() {
+        let near_black = Hct::new(30.0, 12.0, 8.0).to_argb();
+        let blue = Argb(0xff4285f4);
+        let mut colors = IndexMap::new();
+        colors.insert(near_black, 900);
+        colors.insert(blue, 100);
+
+        let unbounded = Score::score_with_options(&colors, ScoreOptions::default());
+        assert_eq!(unbounded[0], near_black);
+
+        let options = ScoreOptions {
+            min_tone: 20.0,
+            max_tone: 80.0,
+            ..ScoreOptions::default()
+        };
+        assert_eq!(Score::score_with_options(&colors, options), vec![blue]);
+        assert!(
+            Score::ranked(&colors, options)
+                .iter()
+                .all(|&(argb, _)| argb != near_black)
+        );
+    }
+
+    #[test]
+    fn test_score_with_counts() {
+        let colors = IndexMap::from([
+            (Argb(0xffff0000), 300),
+            (Argb(0xff00ff00), 200),
+            (Argb(0xff0000ff), 100),
+            (Argb(0xff808080), 400),
+        ]);
+        let options = ScoreOptions::default();
+        let with_counts = Score::score_with_counts(&colors, options);
+
+        let chosen: Vec<Argb> = with_counts.iter().map(|&(argb, _)| argb).collect();
+        assert_eq!(chosen, Score::score_with_options(&colors, options));
+        for &(argb, count) in &with_counts {
+            assert_eq!(count, colors[&argb]);
+        }
+        let total: u32 = colors.values().sum();
+        assert!(with_counts.iter().map(|&(_, count)| count).sum::<u32>() <= total);
+
+        let grey = IndexMap::from([(Argb(0xff808080), 10)]);
+        assert_eq!(
+            Score::score_with_counts(&grey, options),
+            vec![(options.fallback_color_argb, 0)]
+        );
+    }
 }
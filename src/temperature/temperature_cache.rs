@@ -216,6 +216,18 @@ impl TemperatureCache {
         }
     }
 
+    /// Whether the input color is warmer than the midpoint of its chroma and tone.
+    #[must_use]
+    pub fn is_warm(&self) -> bool {
+        self.get_relative_temperature(&self.input) > 0.5
+    }
+
+    /// Whether the input color is cooler than the midpoint of its chroma and tone.
+    #[must_use]
+    pub fn is_cool(&self) -> bool {
+        self.get_relative_temperature(&self.input) < 0.5
+    }
+
     fn get_temp(&self, hct: &Hct) -> f64 {
         let chroma_tone_key = (self.input.chroma().to_bits(), self.input.tone().to_bits());
         let cache = Self::get_global_map(&TEMPS_BY_HCT_CACHE);
@@ -341,3 +353,43 @@ impl TemperatureCache {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_relative_temperature_ordering() {
+        let red = Hct::new(27.0, 30.0, 60.0);
+        let at_hue = |hue: f64| Hct::new(hue, red.chroma(), red.tone());
+        let yellow = at_hue(100.0);
+        let green = at_hue(142.0);
+        let cyan = at_hue(196.0);
+        let blue = at_hue(282.0);
+
+        let cache = TemperatureCache::new(red);
+        let mut colors = [blue, red, cyan, green, yellow];
+        colors.sort_by(|a, b| {
+            cache
+                .get_relative_temperature(a)
+                .total_cmp(&cache.get_relative_temperature(b))
+        });
+
+        assert_eq!(colors[0], cyan);
+        assert_eq!(colors[4], red);
+        for hct in colors {
+            assert!((0.0..=1.0).contains(&cache.get_relative_temperature(&hct)));
+        }
+    }
+
+    #[test]
+    fn test_is_warm_is_cool() {
+        let red = TemperatureCache::new(Hct::new(27.0, 30.0, 60.0));
+        assert!(red.is_warm());
+        assert!(!red.is_cool());
+
+        let cyan = TemperatureCache::new(Hct::new(196.0, 30.0, 60.0));
+        assert!(cyan.is_cool());
+        assert!(!cyan.is_warm());
+    }
+}
@@ -1,5 +1,5 @@
 use crate::hct::{Cam16, Hct};
-use crate::utils::color_utils::{Argb, Lab, Xyz};
+use crate::utils::color_utils::{Argb, Hsl, Lab, Xyz};
 
 // --- Argb Conversions ---
 
@@ -15,6 +15,12 @@ impl From<Lab> for Argb {
     }
 }
 
+impl From<Hsl> for Argb {
+    fn from(hsl: Hsl) -> Self {
+        Self::from_hsl(hsl)
+    }
+}
+
 impl From<Hct> for Argb {
     fn from(hct: Hct) -> Self {
         hct.to_argb()
@@ -98,6 +104,12 @@ impl From<Argb> for Lab {
     }
 }
 
+impl From<Argb> for Hsl {
+    fn from(argb: Argb) -> Self {
+        argb.to_hsl()
+    }
+}
+
 impl From<Argb> for Cam16 {
     fn from(argb: Argb) -> Self {
         Self::from_argb(argb)
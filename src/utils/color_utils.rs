@@ -1,4 +1,5 @@
 use super::math_utils::MathUtils;
+use crate::hct::cam16::Cam16;
 use crate::utils::error::ColorParseError;
 use std::fmt;
 use std::fmt::Display;
@@ -34,6 +35,91 @@ pub struct Lab {
     pub b: f64,
 }
 
+impl Lab {
+    /// CIE76 color difference: the Euclidean distance between two L*a*b* colors.
+    #[must_use]
+    pub fn delta_e_76(&self, other: &Self) -> f64 {
+        let dl = self.l - other.l;
+        let da = self.a - other.a;
+        let db = self.b - other.b;
+        db.mul_add(db, dl.mul_add(dl, da * da)).sqrt()
+    }
+
+    /// CIEDE2000 color difference with unit weighting factors (kL = kC = kH = 1).
+    ///
+    /// Follows Sharma, Wu and Dalal, "The CIEDE2000 Color-Difference Formula: Implementation
+    /// Notes, Supplementary Test Data, and Mathematical Observations" (2005).
+    #[must_use]
+    #[allow(clippy::float_cmp)]
+    pub fn delta_e_2000(&self, other: &Self) -> f64 {
+        const POW_25_7: f64 = 6_103_515_625.0;
+        let (l1, a1, b1) = (self.l, self.a, self.b);
+        let (l2, a2, b2) = (other.l, other.a, other.b);
+
+        let c_bar = (a1.hypot(b1) + a2.hypot(b2)) / 2.0;
+        let c_bar_7 = c_bar.powi(7);
+        let g = 0.5 * (1.0 - (c_bar_7 / (c_bar_7 + POW_25_7)).sqrt());
+        let a1p = (1.0 + g) * a1;
+        let a2p = (1.0 + g) * a2;
+        let c1p = a1p.hypot(b1);
+        let c2p = a2p.hypot(b2);
+        let hue = |b: f64, ap: f64| {
+            if b == 0.0 && ap == 0.0 {
+                0.0
+            } else {
+                b.atan2(ap).to_degrees().rem_euclid(360.0)
+            }
+        };
+        let h1p = hue(b1, a1p);
+        let h2p = hue(b2, a2p);
+        let chroma_product = c1p * c2p;
+
+        let delta_lp = l2 - l1;
+        let delta_cp = c2p - c1p;
+        let delta_hp = if chroma_product == 0.0 {
+            0.0
+        } else if (h2p - h1p).abs() <= 180.0 {
+            h2p - h1p
+        } else if h2p - h1p > 180.0 {
+            h2p - h1p - 360.0
+        } else {
+            h2p - h1p + 360.0
+        };
+        let delta_big_hp = 2.0 * chroma_product.sqrt() * (delta_hp / 2.0).to_radians().sin();
+
+        let l_bar_p = (l1 + l2) / 2.0;
+        let c_bar_p = (c1p + c2p) / 2.0;
+        let h_bar_p = if chroma_product == 0.0 {
+            h1p + h2p
+        } else if (h1p - h2p).abs() <= 180.0 {
+            (h1p + h2p) / 2.0
+        } else if h1p + h2p < 360.0 {
+            (h1p + h2p + 360.0) / 2.0
+        } else {
+            (h1p + h2p - 360.0) / 2.0
+        };
+
+        let cos_deg = |degrees: f64| degrees.to_radians().cos();
+        let t = 1.0 - 0.17 * cos_deg(h_bar_p - 30.0)
+            + 0.24 * cos_deg(2.0 * h_bar_p)
+            + 0.32 * cos_deg(3.0 * h_bar_p + 6.0)
+            - 0.20 * cos_deg(4.0 * h_bar_p - 63.0);
+        let delta_theta = 30.0 * (-((h_bar_p - 275.0) / 25.0).powi(2)).exp();
+        let c_bar_p_7 = c_bar_p.powi(7);
+        let r_c = 2.0 * (c_bar_p_7 / (c_bar_p_7 + POW_25_7)).sqrt();
+        let l_offset = (l_bar_p - 50.0).powi(2);
+        let s_l = 1.0 + 0.015 * l_offset / (20.0 + l_offset).sqrt();
+        let s_c = 1.0 + 0.045 * c_bar_p;
+        let s_h = 1.0 + 0.015 * c_bar_p * t;
+        let r_t = -(2.0 * delta_theta).to_radians().sin() * r_c;
+
+        let l_term = delta_lp / s_l;
+        let c_term = delta_cp / s_c;
+        let h_term = delta_big_hp / s_h;
+        (l_term * l_term + c_term * c_term + h_term * h_term + r_t * c_term * h_term).sqrt()
+    }
+}
+
 /// A color in the XYZ color space.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Xyz {
@@ -42,6 +128,14 @@ pub struct Xyz {
     pub z: f64,
 }
 
+/// A color in the HSL color space. Hue is in degrees, saturation and lightness range from 0 to 1.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Hsl {
+    pub h: f64,
+    pub s: f64,
+    pub l: f64,
+}
+
 impl Argb {
     const SRGB_TO_XYZ: [[f64; 3]; 3] = [
         [0.41233895, 0.35762064, 0.18051042],
@@ -71,30 +165,73 @@ impl Argb {
         Self(0xFF000000 | ((red as u32) << 16) | ((green as u32) << 8) | (blue as u32))
     }
 
-    /// Converts a color from hex RGB string to ARGB format.
+    /// Converts a color from a hex string to ARGB format.
+    ///
+    /// Accepts `RGB`, `ARGB`, `RRGGBB` and `AARRGGBB`, with or without a leading `#`. Short forms
+    /// repeat each digit, so `#F00` is `#FF0000`. Colors without alpha are opaque.
+    ///
     /// # Errors
-    /// If the string can't be parsed to an Argb.
+    /// If the string is empty, has a length other than 3, 4, 6 or 8 digits, or contains a
+    /// character that is not a hex digit.
     pub fn from_hex(hex_string: &str) -> Result<Self, ColorParseError> {
         let hex = hex_string.strip_prefix('#').unwrap_or(hex_string);
-        match hex.len() {
-            6 => {
-                let val = u32::from_str_radix(hex, 16)?;
-                Ok(Self(0xFF000000 | val))
-            }
-            8 => {
-                let val = u32::from_str_radix(hex, 16)?;
-                Ok(Self(val))
+        if hex.is_empty() {
+            return Err(ColorParseError::Empty);
+        }
+        if let Some(invalid) = hex.chars().find(|c| !c.is_ascii_hexdigit()) {
+            return Err(ColorParseError::InvalidCharacter(invalid));
+        }
+        let expanded: String;
+        let hex = match hex.len() {
+            3 | 4 => {
+                expanded = hex.chars().flat_map(|c| [c, c]).collect();
+                &expanded
             }
-            _ => Err(ColorParseError::InvalidLength),
+            6 | 8 => hex,
+            len => return Err(ColorParseError::InvalidLength(len)),
+        };
+        let val = u32::from_str_radix(hex, 16)?;
+        if hex.len() == 6 {
+            Ok(Self(0xFF000000 | val))
+        } else {
+            Ok(Self(val))
         }
     }
 
+    /// Formats the color as `#RRGGBB`, dropping alpha.
     #[must_use]
     pub fn to_hex(&self) -> String {
         format!("#{:02X}{:02X}{:02X}", self.red(), self.green(), self.blue())
     }
 
+    /// Formats the color as `#AARRGGBB`, the Android ordering also accepted by [`Argb::from_hex`].
+    #[must_use]
+    pub fn to_hex_argb(&self) -> String {
+        format!("#{:08X}", self.0)
+    }
+
+    /// Formats the color as `#RRGGBBAA`, the ordering used by CSS.
+    #[must_use]
+    pub fn to_hex_rgba(&self) -> String {
+        format!(
+            "#{:02X}{:02X}{:02X}{:02X}",
+            self.red(),
+            self.green(),
+            self.blue(),
+            self.alpha()
+        )
+    }
+
     /// Converts a color from linear RGB components to ARGB format.
+    ///
+    /// Components are in the 0..100 range used by the HCT solver.
+    ///
+    /// ```
+    /// use material_color_utils::utils::color_utils::Argb;
+    ///
+    /// assert_eq!(Argb::from_linrgb([100.0, 100.0, 100.0]), Argb(0xFFFFFFFF));
+    /// assert_eq!(Argb::from_linrgb([100.0, 0.0, 0.0]), Argb(0xFFFF0000));
+    /// ```
     #[must_use]
     pub fn from_linrgb(linrgb: [f64; 3]) -> Self {
         let r = ColorUtils::delinearized(linrgb[0]);
@@ -202,6 +339,55 @@ impl Argb {
         Lab { l, a, b }
     }
 
+    /// Converts a color from HSL to ARGB. Hue is wrapped to 0..360, saturation and lightness are
+    /// clamped to 0..1.
+    #[must_use]
+    pub fn from_hsl(hsl: Hsl) -> Self {
+        let h = MathUtils::sanitize_degrees_double(hsl.h) / 60.0;
+        let s = hsl.s.clamp(0.0, 1.0);
+        let l = hsl.l.clamp(0.0, 1.0);
+
+        let c = (1.0 - 2.0f64.mul_add(l, -1.0).abs()) * s;
+        let x = c * (1.0 - (h % 2.0 - 1.0).abs());
+        let m = l - c / 2.0;
+        let (r, g, b) = match h as u8 {
+            0 => (c, x, 0.0),
+            1 => (x, c, 0.0),
+            2 => (0.0, c, x),
+            3 => (0.0, x, c),
+            4 => (x, 0.0, c),
+            _ => (c, 0.0, x),
+        };
+        let to_u8 = |v: f64| ((v + m) * 255.0).round().clamp(0.0, 255.0) as u8;
+        Self::from_rgb(to_u8(r), to_u8(g), to_u8(b))
+    }
+
+    /// Converts a color from ARGB to HSL. Alpha is ignored.
+    #[must_use]
+    pub fn to_hsl(&self) -> Hsl {
+        let (red, green, blue) = (self.red(), self.green(), self.blue());
+        let max = red.max(green).max(blue);
+        let min = red.min(green).min(blue);
+        let r = f64::from(red) / 255.0;
+        let g = f64::from(green) / 255.0;
+        let b = f64::from(blue) / 255.0;
+        let l = (f64::from(max) + f64::from(min)) / 510.0;
+        if max == min {
+            return Hsl { h: 0.0, s: 0.0, l };
+        }
+
+        let d = f64::from(max - min) / 255.0;
+        let s = d / (1.0 - 2.0f64.mul_add(l, -1.0).abs());
+        let h = if max == red {
+            ((g - b) / d).rem_euclid(6.0)
+        } else if max == green {
+            (b - r) / d + 2.0
+        } else {
+            (r - g) / d + 4.0
+        };
+        Hsl { h: h * 60.0, s, l }
+    }
+
     /// Converts an L* value to an ARGB representation.
     #[must_use]
     pub fn from_lstar(lstar: f64) -> Self {
@@ -246,17 +432,95 @@ impl ColorUtils {
     }
 
     /// Converts an L* value to a Y value.
+    ///
+    /// Y is relative luminance in the 0..100 range.
+    ///
+    /// ```
+    /// use material_color_utils::utils::color_utils::ColorUtils;
+    ///
+    /// let y = ColorUtils::y_from_lstar(50.0);
+    /// assert!((y - 18.418_651_851_244_416).abs() < 1e-9);
+    /// ```
     #[must_use]
     pub fn y_from_lstar(lstar: f64) -> f64 {
         100.0 * Self::lab_invf((lstar + 16.0) / 116.0)
     }
 
     /// Converts a Y value to an L* value.
+    ///
+    /// ```
+    /// use material_color_utils::utils::color_utils::ColorUtils;
+    ///
+    /// assert_eq!(ColorUtils::lstar_from_y(100.0), 100.0);
+    /// assert!(ColorUtils::lstar_from_y(0.0).abs() < 1e-9);
+    /// ```
     #[must_use]
     pub fn lstar_from_y(y: f64) -> f64 {
         Self::lab_f(y / 100.0) * 116.0 - 16.0
     }
 
+    /// Perceptual distance between two colors, measured in CAM16-UCS.
+    ///
+    /// This is the metric used by the quantizers and scorer; see [`Cam16::distance`].
+    #[must_use]
+    pub fn cam16_distance(a: Argb, b: Argb) -> f64 {
+        Cam16::from_argb(a).distance(&Cam16::from_argb(b))
+    }
+
+    /// CIE76 color difference between two colors in L*a*b*. See [`Lab::delta_e_76`].
+    #[must_use]
+    pub fn delta_e_76(a: Argb, b: Argb) -> f64 {
+        a.to_lab().delta_e_76(&b.to_lab())
+    }
+
+    /// CIEDE2000 color difference between two colors in L*a*b*. See [`Lab::delta_e_2000`].
+    ///
+    /// Unlike [`ColorUtils::cam16_distance`], this is the classic metric most color tools report.
+    #[must_use]
+    pub fn delta_e_2000(a: Argb, b: Argb) -> f64 {
+        a.to_lab().delta_e_2000(&b.to_lab())
+    }
+
+    /// Composites `foreground` over `background` with straight-alpha source-over blending.
+    ///
+    /// `background` is treated as opaque, so the result is always opaque. Useful for resolving
+    /// translucent roles such as `scrim` against the surface they are drawn on.
+    #[must_use]
+    pub fn composite_over(foreground: Argb, background: Argb) -> Argb {
+        let alpha = f64::from(foreground.alpha()) / 255.0;
+        let blend = |fg: u8, bg: u8| {
+            f64::from(fg)
+                .mul_add(alpha, f64::from(bg) * (1.0 - alpha))
+                .round() as u8
+        };
+        Argb::from_rgb(
+            blend(foreground.red(), background.red()),
+            blend(foreground.green(), background.green()),
+            blend(foreground.blue(), background.blue()),
+        )
+    }
+
+    /// Sorts colors from dark to light by their tone (L*). Colors with equal tones keep their
+    /// order.
+    pub fn sort_by_tone(colors: &mut [Argb]) {
+        Self::sort_by_key(colors, Argb::lstar);
+    }
+
+    /// Sorts colors by their HCT hue, starting at 0° (pinkish red). Colors with equal hues, such
+    /// as greys, keep their order.
+    pub fn sort_by_hue(colors: &mut [Argb]) {
+        Self::sort_by_key(colors, |argb| Cam16::from_argb(*argb).hue);
+    }
+
+    /// Stable sort on a float key that is computed once per color.
+    fn sort_by_key(colors: &mut [Argb], key: impl Fn(&Argb) -> f64) {
+        let mut keyed: Vec<(f64, Argb)> = colors.iter().map(|argb| (key(argb), *argb)).collect();
+        keyed.sort_by(|a, b| a.0.total_cmp(&b.0));
+        for (slot, (_, argb)) in colors.iter_mut().zip(keyed) {
+            *slot = argb;
+        }
+    }
+
     /// Returns the standard white point; white on a sunny day.
     #[must_use]
     pub const fn white_point_d65() -> [f64; 3] {
@@ -362,4 +626,170 @@ mod tests {
         // And it should have roughly the same lstar
         assert!((lstar - color_back.lstar()).abs() < 0.1);
     }
+
+    #[test]
+    fn test_hsl_round_trip() {
+        let colors = [
+            (Argb(0xFFFF0000), 0.0),
+            (Argb(0xFF00FF00), 120.0),
+            (Argb(0xFF0000FF), 240.0),
+        ];
+        for (argb, hue) in colors {
+            let hsl = argb.to_hsl();
+            assert!((hsl.h - hue).abs() < 1e-9);
+            assert!((hsl.s - 1.0).abs() < 1e-9);
+            assert!((hsl.l - 0.5).abs() < 1e-9);
+            assert_eq!(Argb::from_hsl(hsl), argb);
+        }
+
+        let grey = Argb(0xFF808080);
+        let hsl = grey.to_hsl();
+        assert!(hsl.s.abs() < 1e-9);
+        assert!((hsl.l - 128.0 / 255.0).abs() < 1e-9);
+        assert_eq!(Argb::from_hsl(hsl), grey);
+
+        for argb in [Argb(0xFF4285F4), Argb(0xFFEA4335), Argb(0xFF34A853)] {
+            assert_eq!(Argb::from_hsl(argb.to_hsl()), argb);
+        }
+    }
+
+    #[test]
+    fn test_cam16_distance() {
+        let color = Argb::from_rgb(123, 45, 67);
+        assert!(ColorUtils::cam16_distance(color, color).abs() < 1e-9);
+
+        let nudged = Argb::from_rgb(124, 45, 67);
+        let small = ColorUtils::cam16_distance(color, nudged);
+        assert!(small > 0.0 && small < 2.0);
+
+        let far = ColorUtils::cam16_distance(color, Argb::from_rgb(20, 200, 240));
+        assert!(far > small);
+    }
+
+    #[test]
+    fn test_delta_e_2000_sharma_pairs() {
+        // Pairs 1-3, 7-8, 13-15, 17-20 and 25 from Sharma, Wu and Dalal (2005), table 1.
+        let pairs = [
+            ((50.0, 2.6772, -79.7751), (50.0, 0.0, -82.7485), 2.0425),
+            ((50.0, 3.1571, -77.2803), (50.0, 0.0, -82.7485), 2.8615),
+            ((50.0, 2.8361, -74.0200), (50.0, 0.0, -82.7485), 3.4412),
+            ((50.0, 0.0, 0.0), (50.0, -1.0, 2.0), 2.3669),
+            ((50.0, -1.0, 2.0), (50.0, 0.0, 0.0), 2.3669),
+            ((50.0, 2.49, -0.001), (50.0, -2.49, 0.0009), 7.1792),
+            ((50.0, 2.49, -0.001), (50.0, -2.49, 0.0010), 7.1792),
+            ((50.0, 2.49, -0.001), (50.0, -2.49, 0.0011), 7.2195),
+            ((50.0, 2.5, 0.0), (73.0, 25.0, -18.0), 27.1492),
+            ((50.0, 2.5, 0.0), (61.0, -5.0, 29.0), 22.8977),
+            ((50.0, 2.5, 0.0), (56.0, -27.0, -3.0), 31.9030),
+            ((50.0, 2.5, 0.0), (58.0, 24.0, 15.0), 19.4535),
+            (
+                (60.2574, -34.0099, 36.2677),
+                (60.4626, -34.1751, 39.4387),
+                1.2644,
+            ),
+        ];
+        for ((l1, a1, b1), (l2, a2, b2), expected) in pairs {
+            let first = Lab {
+                l: l1,
+                a: a1,
+                b: b1,
+            };
+            let second = Lab {
+                l: l2,
+                a: a2,
+                b: b2,
+            };
+            let delta = first.delta_e_2000(&second);
+            assert!((delta - expected).abs() < 1e-4, "{delta} != {expected}");
+        }
+    }
+
+    #[test]
+    fn test_delta_e_argb() {
+        let color = Argb::from_rgb(123, 45, 67);
+        assert!(ColorUtils::delta_e_76(color, color).abs() < 1e-9);
+        assert!(ColorUtils::delta_e_2000(color, color).abs() < 1e-9);
+
+        let black_to_white = ColorUtils::delta_e_76(Argb(0xFF000000), Argb(0xFFFFFFFF));
+        assert!((black_to_white - 100.0).abs() < 1e-3, "{black_to_white}");
+        let black_to_white = ColorUtils::delta_e_2000(Argb(0xFF000000), Argb(0xFFFFFFFF));
+        assert!((black_to_white - 100.0).abs() < 1e-3, "{black_to_white}");
+    }
+
+    #[test]
+    fn test_composite_over() {
+        let half_black = Argb(0x80000000);
+        let grey = ColorUtils::composite_over(half_black, Argb(0xFFFFFFFF));
+        assert!(grey.is_opaque());
+        assert_eq!(grey.red(), grey.green());
+        assert_eq!(grey.green(), grey.blue());
+        assert!((127..=128).contains(&grey.red()));
+
+        let red = Argb(0xFFFF0000);
+        assert_eq!(ColorUtils::composite_over(red, Argb(0xFF0000FF)), red);
+        assert_eq!(
+            ColorUtils::composite_over(Argb(0x00FF0000), Argb(0xFF0000FF)),
+            Argb(0xFF0000FF)
+        );
+    }
+
+    #[test]
+    fn test_hex_with_alpha() {
+        let color = Argb(0x80ff0000);
+        assert_eq!(color.to_hex(), "#FF0000");
+        assert_eq!(color.to_hex_argb(), "#80FF0000");
+        assert_eq!(color.to_hex_rgba(), "#FF000080");
+        assert_eq!(Argb::from_hex(&color.to_hex_argb()).ok(), Some(color));
+    }
+
+    #[test]
+    fn test_from_hex_forms() {
+        assert_eq!(Argb::from_hex("#4285f4").ok(), Some(Argb(0xFF4285F4)));
+        assert_eq!(Argb::from_hex("4285F4").ok(), Some(Argb(0xFF4285F4)));
+        assert_eq!(Argb::from_hex("#804285F4").ok(), Some(Argb(0x804285F4)));
+        assert_eq!(Argb::from_hex("#f00").ok(), Some(Argb(0xFFFF0000)));
+        assert_eq!(Argb::from_hex("8f00").ok(), Some(Argb(0x88FF0000)));
+    }
+
+    #[test]
+    fn test_from_hex_errors() {
+        assert!(matches!(Argb::from_hex(""), Err(ColorParseError::Empty)));
+        assert!(matches!(Argb::from_hex("#"), Err(ColorParseError::Empty)));
+        assert!(matches!(
+            Argb::from_hex("#ggg"),
+            Err(ColorParseError::InvalidCharacter('g'))
+        ));
+        assert!(matches!(
+            Argb::from_hex("+12345"),
+            Err(ColorParseError::InvalidCharacter('+'))
+        ));
+        assert!(matches!(
+            Argb::from_hex("#12345"),
+            Err(ColorParseError::InvalidLength(5))
+        ));
+    }
+
+    #[test]
+    fn test_sort_by_tone_and_hue() {
+        let mut greys: Vec<Argb> = [0x80, 0x10, 0xF0, 0x40, 0x00, 0xC0, 0xFF]
+            .into_iter()
+            .map(|v| Argb::from_rgb(v, v, v))
+            .collect();
+        ColorUtils::sort_by_tone(&mut greys);
+        let tones: Vec<f64> = greys.iter().map(Argb::lstar).collect();
+        assert!(tones.is_sorted(), "{tones:?}");
+        assert_eq!(greys.first(), Some(&Argb(0xFF000000)));
+        assert_eq!(greys.last(), Some(&Argb(0xFFFFFFFF)));
+
+        // HCT hues: red ~27, yellow ~111, green ~142, blue ~283.
+        let (red, yellow, green, blue) = (
+            Argb(0xFFFF0000),
+            Argb(0xFFFFFF00),
+            Argb(0xFF00FF00),
+            Argb(0xFF0000FF),
+        );
+        let mut colors = vec![blue, green, red, yellow];
+        ColorUtils::sort_by_hue(&mut colors);
+        assert_eq!(colors, [red, yellow, green, blue]);
+    }
 }
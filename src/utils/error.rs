@@ -1,10 +1,28 @@
 use thiserror::Error;
 
+/// Why a hex color string couldn't be parsed.
+///
+/// New variants may be added as parsing gets more specific, so match with a wildcard arm.
 #[derive(Debug, Error)]
+#[non_exhaustive]
 pub enum ColorParseError {
-    #[error("Hex string must be 6 or 8 characters (plus optional #)")]
-    InvalidLength,
+    #[error("Hex string is empty")]
+    Empty,
+
+    #[error("Hex string must be 3, 4, 6 or 8 characters (plus optional #), got {0}")]
+    InvalidLength(usize),
+
+    #[error("Invalid hex character: {0:?}")]
+    InvalidCharacter(char),
 
     #[error("Invalid hex characters: {0}")]
     InvalidHex(#[from] std::num::ParseIntError),
 }
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("Unknown variant: {0}")]
+pub struct VariantParseError(pub String);
+
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+#[error("Unknown spec version: {0}")]
+pub struct SpecVersionParseError(pub String);
@@ -49,6 +49,14 @@ impl MathUtils {
     /// # Returns
     /// -1.0 if decreasing `from` leads to the shortest travel distance, 1.0 if increasing `from` leads
     /// to the shortest travel distance.
+    ///
+    /// ```
+    /// use material_color_utils::utils::math_utils::MathUtils;
+    ///
+    /// // The short way from 350° to 10° goes up through 0°.
+    /// assert_eq!(MathUtils::rotation_direction(350.0, 10.0), 1.0);
+    /// assert_eq!(MathUtils::rotation_direction(10.0, 350.0), -1.0);
+    /// ```
     #[must_use]
     pub fn rotation_direction(from: f64, to: f64) -> f64 {
         let increasing_difference = Self::sanitize_degrees_double(to - from);
@@ -60,6 +68,16 @@ impl MathUtils {
     }
 
     /// Distance of two points on a circle, represented using degrees.
+    ///
+    /// The result is the shorter way around, from 0 to 180. Both angles are expected to be
+    /// sanitized to `0.0..360.0`, see [`MathUtils::sanitize_degrees_double`].
+    ///
+    /// ```
+    /// use material_color_utils::utils::math_utils::MathUtils;
+    ///
+    /// assert_eq!(MathUtils::difference_degrees(350.0, 10.0), 20.0);
+    /// assert_eq!(MathUtils::difference_degrees(90.0, 270.0), 180.0);
+    /// ```
     #[must_use]
     pub fn difference_degrees(a: f64, b: f64) -> f64 {
         180.0 - ((a - b).abs() - 180.0).abs()
@@ -133,6 +151,18 @@ mod tests {
         assert_eq!(MathUtils::difference_degrees(0.0, 190.0), 170.0);
         assert_eq!(MathUtils::difference_degrees(190.0, 0.0), 170.0);
         assert_eq!(MathUtils::difference_degrees(350.0, 10.0), 20.0);
+
+        // Symmetric, within 0..=180, and consistent with the direction of travel.
+        for a in (0..360).step_by(15) {
+            for b in (0..360).step_by(15) {
+                let (a, b) = (f64::from(a), f64::from(b));
+                let difference = MathUtils::difference_degrees(a, b);
+                assert!((0.0..=180.0).contains(&difference));
+                assert_eq!(difference, MathUtils::difference_degrees(b, a));
+                let rotated = difference.mul_add(MathUtils::rotation_direction(a, b), a);
+                assert_eq!(MathUtils::sanitize_degrees_double(rotated), b, "{a} -> {b}");
+            }
+        }
     }
 
     #[test]
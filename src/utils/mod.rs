@@ -1,4 +1,5 @@
 pub mod color_conversion_traits;
 pub mod color_utils;
+mod css_colors;
 pub mod error;
 pub mod math_utils;